@@ -0,0 +1,244 @@
+/*
+ * Copyright 2020 fsyncd, Berlin, Germany.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::Spec;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Name of the configuration file inside a bundle directory.
+pub const CONFIG_FILE: &str = "config.json";
+
+/// Distinguishes the temporary files of concurrent saves within one process.
+static SAVE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// BundleError is returned when a bundle cannot be loaded or saved.
+#[derive(Debug)]
+pub enum BundleError {
+    /// Reading or writing a bundle file failed.
+    Io(io::Error),
+    /// The configuration could not be parsed or serialized.
+    Json(serde_json::Error),
+    /// The root filesystem referenced by the configuration does not exist.
+    RootfsNotFound(PathBuf),
+}
+
+impl fmt::Display for BundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BundleError::Io(err) => write!(f, "bundle i/o error: {}", err),
+            BundleError::Json(err) => write!(f, "invalid {}: {}", CONFIG_FILE, err),
+            BundleError::RootfsNotFound(path) => {
+                write!(f, "root filesystem {} does not exist", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for BundleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BundleError::Io(err) => Some(err),
+            BundleError::Json(err) => Some(err),
+            BundleError::RootfsNotFound(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for BundleError {
+    fn from(err: io::Error) -> Self {
+        BundleError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for BundleError {
+    fn from(err: serde_json::Error) -> Self {
+        BundleError::Json(err)
+    }
+}
+
+/// Bundle is a directory holding a container's `config.json` and its root filesystem.
+#[derive(Clone, Debug)]
+pub struct Bundle {
+    path: PathBuf,
+    spec: Spec,
+}
+
+impl Bundle {
+    /// Creates a bundle rooted at `path` for the given spec, without touching the filesystem.
+    pub fn new<P: Into<PathBuf>>(path: P, spec: Spec) -> Self {
+        Bundle {
+            path: path.into(),
+            spec,
+        }
+    }
+
+    /// Loads `<path>/config.json` and checks that the root filesystem it references exists.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BundleError> {
+        let path = path.as_ref().to_path_buf();
+        let file = File::open(path.join(CONFIG_FILE))?;
+        let spec = serde_json::from_reader(BufReader::new(file))?;
+        let bundle = Bundle { path, spec };
+        if let Some(rootfs) = bundle.rootfs() {
+            if !rootfs.is_dir() {
+                return Err(BundleError::RootfsNotFound(rootfs));
+            }
+        }
+        Ok(bundle)
+    }
+
+    /// Path is the bundle directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of the bundle's `config.json`.
+    pub fn config_path(&self) -> PathBuf {
+        self.path.join(CONFIG_FILE)
+    }
+
+    /// Returns the root filesystem path, resolved relative to the bundle directory.
+    /// Absolute root paths are returned unchanged.
+    pub fn rootfs(&self) -> Option<PathBuf> {
+        self.spec.root().map(|root| self.path.join(root.path()))
+    }
+
+    /// Spec is the container configuration of the bundle.
+    pub fn spec(&self) -> &Spec {
+        &self.spec
+    }
+
+    /// Mutable access to [`spec`](Self::spec).
+    pub fn spec_mut(&mut self) -> &mut Spec {
+        &mut self.spec
+    }
+
+    /// Consumes the bundle, returning its spec.
+    pub fn into_spec(self) -> Spec {
+        self.spec
+    }
+
+    /// Writes the spec to `config.json`. The configuration is written to a temporary file
+    /// in the bundle directory first and then renamed over the old one, so readers never
+    /// observe a partially written file. The bundle directory is synced after the rename so
+    /// that the new file survives a crash.
+    pub fn save(&self) -> Result<(), BundleError> {
+        let tmp = self.path.join(format!(
+            ".{}.{}.{}.tmp",
+            CONFIG_FILE,
+            std::process::id(),
+            SAVE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = self.write_config(&tmp);
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        result
+    }
+
+    fn write_config(&self, tmp: &Path) -> Result<(), BundleError> {
+        let file = OpenOptions::new().write(true).create_new(true).open(tmp)?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut writer, &self.spec)?;
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        fs::rename(tmp, self.config_path())?;
+        sync_dir(&self.path)
+    }
+}
+
+/// Flushes a directory's entries to disk. Directories cannot be opened as files on
+/// Windows, where the rename is already durable once it returns.
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> Result<(), BundleError> {
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> Result<(), BundleError> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    /// Creates an empty bundle directory with a `rootfs` under the system temp directory.
+    fn bundle_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("container-spec-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("rootfs")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = bundle_dir("round-trip");
+        let bundle = Bundle::new(&dir, Spec::default_linux());
+        bundle.save().unwrap();
+        let loaded = Bundle::load(&dir).unwrap();
+        assert_eq!(
+            serde_json::to_value(loaded.spec()).unwrap(),
+            serde_json::to_value(bundle.spec()).unwrap()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn concurrent_saves_leave_a_complete_config() {
+        let dir = bundle_dir("concurrent");
+        let bundle = Arc::new(Bundle::new(&dir, Spec::default_linux()));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let bundle = Arc::clone(&bundle);
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        bundle.save().unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(Bundle::load(&dir).is_ok());
+        let leftovers: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .filter(|name| name.to_string_lossy().ends_with(".tmp"))
+            .collect();
+        assert!(leftovers.is_empty(), "{:?}", leftovers);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn load_reports_missing_rootfs() {
+        let dir = bundle_dir("missing-rootfs");
+        Bundle::new(&dir, Spec::default_linux()).save().unwrap();
+        fs::remove_dir(dir.join("rootfs")).unwrap();
+        match Bundle::load(&dir) {
+            Err(BundleError::RootfsNotFound(path)) => assert_eq!(path, dir.join("rootfs")),
+            other => panic!("unexpected result: {:?}", other.map(|_| ())),
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,596 @@
+/*
+ * Copyright 2020 fsyncd, Berlin, Germany.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::validate::{ValidationErrorKind, Validator};
+use crate::{LinuxCapabilities, ParseError, ValidationError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+macro_rules! capabilities {
+    ($($(#[$doc:meta])* $variant:ident = $value:literal => $name:literal,)*) => {
+        /// Capability is a Linux capability, see capabilities(7).
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[repr(u8)]
+        pub enum Capability {
+            $($(#[$doc])* #[serde(rename = $name)] $variant = $value,)*
+        }
+
+        impl Capability {
+            /// All capabilities known to this crate, ordered by their numeric value.
+            pub const ALL: &'static [Capability] = &[$(Capability::$variant,)*];
+
+            /// Returns the `CAP_*` name of the capability.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Capability::$variant => $name,)*
+                }
+            }
+
+            /// Returns the capability with the given kernel number.
+            pub fn from_value(value: u8) -> Option<Self> {
+                match value {
+                    $($value => Some(Capability::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl FromStr for Capability {
+            type Err = ParseError;
+
+            /// Parses `CAP_NET_ADMIN` as well as the short, case-insensitive form `net_admin`.
+            fn from_str(s: &str) -> Result<Self, ParseError> {
+                let upper = s.to_ascii_uppercase();
+                let name = if upper.starts_with("CAP_") { upper } else { format!("CAP_{}", upper) };
+                match name.as_str() {
+                    $($name => Ok(Capability::$variant),)*
+                    _ => Err(ParseError::new("Capability", s)),
+                }
+            }
+        }
+    };
+}
+
+capabilities! {
+    /// Make arbitrary changes to file UIDs and GIDs.
+    Chown = 0 => "CAP_CHOWN",
+    /// Bypass file read, write, and execute permission checks.
+    DacOverride = 1 => "CAP_DAC_OVERRIDE",
+    /// Bypass file read permission and directory read and execute permission checks.
+    DacReadSearch = 2 => "CAP_DAC_READ_SEARCH",
+    /// Bypass permission checks on operations requiring the file UID to match.
+    Fowner = 3 => "CAP_FOWNER",
+    /// Don't clear set-user-ID and set-group-ID mode bits when a file is modified.
+    Fsetid = 4 => "CAP_FSETID",
+    /// Bypass permission checks for sending signals.
+    Kill = 5 => "CAP_KILL",
+    /// Make arbitrary manipulations of process GIDs and supplementary GID list.
+    Setgid = 6 => "CAP_SETGID",
+    /// Make arbitrary manipulations of process UIDs.
+    Setuid = 7 => "CAP_SETUID",
+    /// Modify the capability bounding set and transfer capabilities.
+    Setpcap = 8 => "CAP_SETPCAP",
+    /// Set the FS_APPEND_FL and FS_IMMUTABLE_FL inode flags.
+    LinuxImmutable = 9 => "CAP_LINUX_IMMUTABLE",
+    /// Bind a socket to privileged ports.
+    NetBindService = 10 => "CAP_NET_BIND_SERVICE",
+    /// Make socket broadcasts and listen to multicasts (unused).
+    NetBroadcast = 11 => "CAP_NET_BROADCAST",
+    /// Perform various network-related operations.
+    NetAdmin = 12 => "CAP_NET_ADMIN",
+    /// Use RAW and PACKET sockets.
+    NetRaw = 13 => "CAP_NET_RAW",
+    /// Lock memory.
+    IpcLock = 14 => "CAP_IPC_LOCK",
+    /// Bypass permission checks for operations on System V IPC objects.
+    IpcOwner = 15 => "CAP_IPC_OWNER",
+    /// Load and unload kernel modules.
+    SysModule = 16 => "CAP_SYS_MODULE",
+    /// Perform I/O port operations.
+    SysRawio = 17 => "CAP_SYS_RAWIO",
+    /// Use chroot(2).
+    SysChroot = 18 => "CAP_SYS_CHROOT",
+    /// Trace arbitrary processes using ptrace(2).
+    SysPtrace = 19 => "CAP_SYS_PTRACE",
+    /// Use acct(2).
+    SysPacct = 20 => "CAP_SYS_PACCT",
+    /// Perform a range of system administration operations.
+    SysAdmin = 21 => "CAP_SYS_ADMIN",
+    /// Use reboot(2) and kexec_load(2).
+    SysBoot = 22 => "CAP_SYS_BOOT",
+    /// Raise process nice values and change scheduling policies.
+    SysNice = 23 => "CAP_SYS_NICE",
+    /// Override resource limits.
+    SysResource = 24 => "CAP_SYS_RESOURCE",
+    /// Set the system clock.
+    SysTime = 25 => "CAP_SYS_TIME",
+    /// Use vhangup(2) and privileged ioctl(2) operations on virtual terminals.
+    SysTtyConfig = 26 => "CAP_SYS_TTY_CONFIG",
+    /// Create special files using mknod(2).
+    Mknod = 27 => "CAP_MKNOD",
+    /// Establish leases on arbitrary files.
+    Lease = 28 => "CAP_LEASE",
+    /// Write records to the kernel auditing log.
+    AuditWrite = 29 => "CAP_AUDIT_WRITE",
+    /// Enable and disable kernel auditing.
+    AuditControl = 30 => "CAP_AUDIT_CONTROL",
+    /// Set arbitrary capabilities on a file.
+    Setfcap = 31 => "CAP_SETFCAP",
+    /// Override Mandatory Access Control.
+    MacOverride = 32 => "CAP_MAC_OVERRIDE",
+    /// Allow MAC configuration or state changes.
+    MacAdmin = 33 => "CAP_MAC_ADMIN",
+    /// Perform privileged syslog(2) operations.
+    Syslog = 34 => "CAP_SYSLOG",
+    /// Trigger something that will wake up the system.
+    WakeAlarm = 35 => "CAP_WAKE_ALARM",
+    /// Employ features that can block system suspend.
+    BlockSuspend = 36 => "CAP_BLOCK_SUSPEND",
+    /// Read the audit log via a multicast netlink socket.
+    AuditRead = 37 => "CAP_AUDIT_READ",
+    /// Employ performance monitoring mechanisms.
+    Perfmon = 38 => "CAP_PERFMON",
+    /// Employ privileged BPF operations.
+    Bpf = 39 => "CAP_BPF",
+    /// Employ checkpoint/restore related operations.
+    CheckpointRestore = 40 => "CAP_CHECKPOINT_RESTORE",
+}
+
+impl Capability {
+    /// Returns the kernel number of the capability.
+    pub fn value(self) -> u8 {
+        self as u8
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// CapabilitySet is a set of capabilities, stored as a bitmask like the kernel does.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CapabilitySet(u64);
+
+impl CapabilitySet {
+    /// Returns an empty set.
+    pub fn new() -> Self {
+        CapabilitySet(0)
+    }
+
+    /// Returns the set of all known capabilities.
+    pub fn all() -> Self {
+        Capability::ALL.iter().copied().collect()
+    }
+
+    /// Parses a list of capability names, such as the sets of [`LinuxCapabilities`].
+    pub fn from_names<I, S>(names: I) -> Result<Self, ParseError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        names
+            .into_iter()
+            .map(|name| name.as_ref().parse())
+            .collect()
+    }
+
+    /// Returns the `CAP_*` names of the capabilities in the set.
+    pub fn to_names(&self) -> Vec<String> {
+        self.iter().map(|cap| cap.as_str().to_string()).collect()
+    }
+
+    /// Reports whether the set contains `cap`.
+    pub fn contains(&self, cap: Capability) -> bool {
+        self.0 & (1 << cap.value()) != 0
+    }
+
+    /// Adds `cap` to the set, returning whether it was newly inserted.
+    pub fn insert(&mut self, cap: Capability) -> bool {
+        let inserted = !self.contains(cap);
+        self.0 |= 1 << cap.value();
+        inserted
+    }
+
+    /// Removes `cap` from the set, returning whether it was present.
+    pub fn remove(&mut self, cap: Capability) -> bool {
+        let present = self.contains(cap);
+        self.0 &= !(1 << cap.value());
+        present
+    }
+
+    /// Reports whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of capabilities in the set.
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Iterates over the capabilities in the set in numeric order.
+    pub fn iter(&self) -> impl Iterator<Item = Capability> + '_ {
+        Capability::ALL
+            .iter()
+            .copied()
+            .filter(move |cap| self.contains(*cap))
+    }
+
+    /// Returns the capabilities in either set.
+    pub fn union(&self, other: &CapabilitySet) -> CapabilitySet {
+        CapabilitySet(self.0 | other.0)
+    }
+
+    /// Returns the capabilities in both sets.
+    pub fn intersection(&self, other: &CapabilitySet) -> CapabilitySet {
+        CapabilitySet(self.0 & other.0)
+    }
+
+    /// Returns the capabilities in this set but not in `other`.
+    pub fn difference(&self, other: &CapabilitySet) -> CapabilitySet {
+        CapabilitySet(self.0 & !other.0)
+    }
+
+    /// Reports whether every capability in this set is also in `other`.
+    pub fn is_subset(&self, other: &CapabilitySet) -> bool {
+        self.0 & !other.0 == 0
+    }
+}
+
+impl fmt::Debug for CapabilitySet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Capability> for CapabilitySet {
+    fn from_iter<I: IntoIterator<Item = Capability>>(iter: I) -> Self {
+        let mut set = CapabilitySet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Capability> for CapabilitySet {
+    fn extend<I: IntoIterator<Item = Capability>>(&mut self, iter: I) {
+        for cap in iter {
+            self.insert(cap);
+        }
+    }
+}
+
+fn names_contain(names: &[String], cap: Capability) -> bool {
+    names.iter().any(|name| name.parse() == Ok(cap))
+}
+
+/// Returns the canonical `CAP_*` name for a known capability, or `name` itself otherwise.
+fn canonical(name: &str) -> &str {
+    name.parse::<Capability>()
+        .map(|cap| cap.as_str())
+        .unwrap_or(name)
+}
+
+/// Reports whether `name` has the `CAP_*` form of a capability, known or not.
+fn is_well_formed(name: &str) -> bool {
+    name.strip_prefix("CAP_").is_some_and(|rest| {
+        !rest.is_empty()
+            && rest
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+    })
+}
+
+impl LinuxCapabilities {
+    /// Grants `cap` by adding it to the bounding, effective and permitted sets.
+    pub fn add(&mut self, cap: Capability) {
+        for names in [&mut self.bounding, &mut self.effective, &mut self.permitted] {
+            if !names_contain(names, cap) {
+                names.push(cap.as_str().to_string());
+            }
+        }
+    }
+
+    /// Removes `cap` from all five sets.
+    /// Names that do not parse as a known capability are left in place.
+    pub fn drop(&mut self, cap: Capability) {
+        self.retain(|c| c != cap, true);
+    }
+
+    /// Removes every capability from all five sets.
+    pub fn drop_all(&mut self) {
+        self.bounding.clear();
+        self.effective.clear();
+        self.inheritable.clear();
+        self.permitted.clear();
+        self.ambient.clear();
+    }
+
+    /// Removes every capability not in `keep` from all five sets.
+    /// Names that do not parse as a known capability cannot be in `keep`, so they are
+    /// removed as well.
+    pub fn keep_only(&mut self, keep: &CapabilitySet) {
+        self.retain(|c| keep.contains(c), false);
+    }
+
+    /// Keeps the names for which `f` returns true; names that do not parse as a known
+    /// capability are kept if `keep_unknown` is set.
+    fn retain<F: Fn(Capability) -> bool>(&mut self, f: F, keep_unknown: bool) {
+        for names in [
+            &mut self.bounding,
+            &mut self.effective,
+            &mut self.inheritable,
+            &mut self.permitted,
+            &mut self.ambient,
+        ] {
+            names.retain(|name| name.parse().map(&f).unwrap_or(keep_unknown));
+        }
+    }
+
+    /// Returns the `CAP_*` names that are not known to this crate, such as capabilities
+    /// added by a newer kernel. Runtimes accept these with a warning, so validation does not
+    /// reject them; callers that want to warn as well can use this list.
+    pub fn unknown(&self) -> Vec<&str> {
+        let mut unknown: Vec<&str> = Vec::new();
+        for names in [
+            &self.bounding,
+            &self.effective,
+            &self.inheritable,
+            &self.permitted,
+            &self.ambient,
+        ] {
+            for name in names {
+                if name.parse::<Capability>().is_err()
+                    && is_well_formed(name)
+                    && !unknown.contains(&name.as_str())
+                {
+                    unknown.push(name);
+                }
+            }
+        }
+        unknown
+    }
+
+    /// Checks that every name is a capability, that the effective set is a subset of the
+    /// permitted set, and that the ambient set is a subset of both the permitted and the
+    /// inheritable sets, as the kernel requires. Well-formed names this crate does not know
+    /// are accepted and reported by [`unknown`](Self::unknown) instead.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut v = Validator::new();
+        validate_capabilities(&mut v, "", self);
+        v.finish()
+    }
+}
+
+pub(crate) fn validate_capabilities(v: &mut Validator, path: &str, caps: &LinuxCapabilities) {
+    let sets: [(&str, &[String]); 5] = [
+        ("bounding", &caps.bounding),
+        ("effective", &caps.effective),
+        ("inheritable", &caps.inheritable),
+        ("permitted", &caps.permitted),
+        ("ambient", &caps.ambient),
+    ];
+    for (set, names) in sets.iter() {
+        for (i, name) in names.iter().enumerate() {
+            if let Err(err) = name.parse::<Capability>() {
+                if !is_well_formed(name) {
+                    v.fail(
+                        format!("{}/{}/{}", path, set, i),
+                        ValidationErrorKind::Invalid(err.to_string()),
+                    );
+                }
+            }
+        }
+    }
+    let permitted: HashSet<&str> = caps.permitted.iter().map(|n| canonical(n)).collect();
+    let inheritable: HashSet<&str> = caps.inheritable.iter().map(|n| canonical(n)).collect();
+    for (i, name) in caps.effective.iter().enumerate() {
+        let cap = canonical(name);
+        if !permitted.contains(cap) {
+            let reason = format!("{} is effective but not permitted", cap);
+            v.fail(
+                format!("{}/effective/{}", path, i),
+                ValidationErrorKind::Conflict(reason),
+            );
+        }
+    }
+    for (i, name) in caps.ambient.iter().enumerate() {
+        let cap = canonical(name);
+        if !permitted.contains(cap) || !inheritable.contains(cap) {
+            let reason = format!("{} is ambient but not both permitted and inheritable", cap);
+            v.fail(
+                format!("{}/ambient/{}", path, i),
+                ValidationErrorKind::Conflict(reason),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinuxCapabilitiesBuilder;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn caps(permitted: &[&str], effective: &[&str]) -> LinuxCapabilities {
+        LinuxCapabilitiesBuilder::default()
+            .permitted(names(permitted))
+            .effective(names(effective))
+            .build()
+            .unwrap()
+    }
+
+    fn paths(caps: &LinuxCapabilities) -> Vec<String> {
+        match caps.validate() {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.iter().map(|e| e.path().to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn unknown_names_are_accepted_and_reported() {
+        let caps = caps(&["CAP_CHOWN", "CAP_FUTURE"], &["CAP_FUTURE"]);
+        assert!(caps.validate().is_ok());
+        assert_eq!(caps.unknown(), vec!["CAP_FUTURE"]);
+    }
+
+    #[test]
+    fn malformed_names_are_rejected() {
+        let caps = caps(&["CAP_CHOWN", "not a cap"], &[]);
+        assert_eq!(paths(&caps), vec!["/permitted/1"]);
+        assert!(caps.unknown().is_empty());
+    }
+
+    #[test]
+    fn unknown_names_take_part_in_subset_checks() {
+        let caps = caps(&["CAP_CHOWN"], &["chown", "CAP_FUTURE"]);
+        assert_eq!(paths(&caps), vec!["/effective/1"]);
+    }
+
+    /// Returns capabilities with `list` in all five sets.
+    fn full(list: &[&str]) -> LinuxCapabilities {
+        LinuxCapabilitiesBuilder::default()
+            .bounding(names(list))
+            .effective(names(list))
+            .inheritable(names(list))
+            .permitted(names(list))
+            .ambient(names(list))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_long_and_short_names() {
+        assert_eq!("CAP_NET_ADMIN".parse(), Ok(Capability::NetAdmin));
+        assert_eq!("net_admin".parse(), Ok(Capability::NetAdmin));
+        assert_eq!("Cap_Net_Admin".parse(), Ok(Capability::NetAdmin));
+        assert!("CAP_NET_ADMINS".parse::<Capability>().is_err());
+        assert!("".parse::<Capability>().is_err());
+        assert_eq!(Capability::NetAdmin.to_string(), "CAP_NET_ADMIN");
+        assert_eq!(Capability::NetAdmin.value(), 12);
+        assert_eq!(Capability::from_value(12), Some(Capability::NetAdmin));
+        assert_eq!(
+            serde_json::to_value(Capability::NetAdmin).unwrap(),
+            "CAP_NET_ADMIN"
+        );
+    }
+
+    #[test]
+    fn capability_set_operations() {
+        let mut set = CapabilitySet::from_names(["CAP_CHOWN", "kill"]).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(Capability::Kill));
+        assert!(set.insert(Capability::SysAdmin));
+        assert!(!set.insert(Capability::SysAdmin));
+        assert!(set.remove(Capability::Chown));
+        assert!(!set.remove(Capability::Chown));
+        assert_eq!(set.to_names(), ["CAP_KILL", "CAP_SYS_ADMIN"]);
+
+        let other: CapabilitySet = [Capability::Kill, Capability::NetRaw]
+            .iter()
+            .copied()
+            .collect();
+        let union: Vec<Capability> = set.union(&other).iter().collect();
+        assert_eq!(
+            union,
+            [Capability::Kill, Capability::NetRaw, Capability::SysAdmin]
+        );
+        assert_eq!(set.intersection(&other).to_names(), ["CAP_KILL"]);
+        assert_eq!(set.difference(&other).to_names(), ["CAP_SYS_ADMIN"]);
+        assert!(set.intersection(&other).is_subset(&set));
+        assert!(!set.is_subset(&other));
+        assert!(CapabilitySet::new().is_empty());
+        assert_eq!(CapabilitySet::all().len(), Capability::ALL.len());
+        assert!(CapabilitySet::from_names(["CAP_CHOWN", "CAP_FUTURE"]).is_err());
+    }
+
+    #[test]
+    fn add_grants_bounding_effective_and_permitted() {
+        let mut caps = caps(&["CAP_KILL"], &["kill"]);
+        caps.add(Capability::Kill);
+        caps.add(Capability::NetBindService);
+        assert_eq!(caps.bounding(), ["CAP_KILL", "CAP_NET_BIND_SERVICE"]);
+        assert_eq!(caps.effective(), ["kill", "CAP_NET_BIND_SERVICE"]);
+        assert_eq!(caps.permitted(), ["CAP_KILL", "CAP_NET_BIND_SERVICE"]);
+        assert!(caps.inheritable().is_empty());
+        assert!(caps.ambient().is_empty());
+    }
+
+    #[test]
+    fn drop_keeps_unknown_names() {
+        let mut caps = full(&["CAP_CHOWN", "chown", "CAP_KILL", "CAP_FUTURE"]);
+        caps.drop(Capability::Chown);
+        for set in [
+            caps.bounding(),
+            caps.effective(),
+            caps.inheritable(),
+            caps.permitted(),
+            caps.ambient(),
+        ] {
+            assert_eq!(set, ["CAP_KILL", "CAP_FUTURE"]);
+        }
+    }
+
+    #[test]
+    fn keep_only_removes_unknown_names() {
+        let mut caps = full(&["CAP_CHOWN", "CAP_KILL", "CAP_FUTURE"]);
+        caps.keep_only(&[Capability::Kill].iter().copied().collect());
+        for set in [
+            caps.bounding(),
+            caps.effective(),
+            caps.inheritable(),
+            caps.permitted(),
+            caps.ambient(),
+        ] {
+            assert_eq!(set, ["CAP_KILL"]);
+        }
+    }
+
+    #[test]
+    fn drop_all_clears_every_set() {
+        let mut caps = full(&["CAP_CHOWN", "CAP_FUTURE"]);
+        caps.drop_all();
+        assert_eq!(serde_json::to_value(&caps).unwrap(), serde_json::json!({}));
+    }
+
+    #[test]
+    fn effective_must_be_permitted() {
+        assert!(caps(&["CAP_CHOWN", "CAP_KILL"], &["CAP_KILL"])
+            .validate()
+            .is_ok());
+        let caps = caps(&["CAP_CHOWN"], &["CAP_CHOWN", "CAP_KILL"]);
+        assert_eq!(paths(&caps), vec!["/effective/1"]);
+    }
+
+    #[test]
+    fn ambient_must_be_permitted_and_inheritable() {
+        let caps = LinuxCapabilitiesBuilder::default()
+            .permitted(names(&["CAP_CHOWN", "CAP_KILL"]))
+            .inheritable(names(&["CAP_CHOWN", "CAP_NET_RAW"]))
+            .ambient(names(&["chown", "CAP_KILL", "CAP_NET_RAW"]))
+            .build()
+            .unwrap();
+        assert_eq!(paths(&caps), vec!["/ambient/1", "/ambient/2"]);
+        assert!(full(&["CAP_CHOWN", "CAP_FUTURE"]).validate().is_ok());
+    }
+}
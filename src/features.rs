@@ -0,0 +1,694 @@
+/*
+ * Copyright 2020 fsyncd, Berlin, Germany.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Types for the runtime features document printed by `runc features`.
+//!
+//! Values a runtime reports are kept as strings, so that a document from a runtime newer than
+//! this crate can still be read.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Features represents the supported features of the runtime.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Features {
+    /// OCIVersionMin is the minimum OCI Runtime Spec version recognized by the runtime, e.g., "1.0.0".
+    #[serde(rename = "ociVersionMin")]
+    oci_version_min: String,
+    /// OCIVersionMax is the maximum OCI Runtime Spec version recognized by the runtime, e.g., "1.0.2-dev".
+    #[serde(rename = "ociVersionMax")]
+    oci_version_max: String,
+    /// Hooks is the list of the recognized hook names, e.g., "createRuntime".
+    /// Nil value means "unknown", not "no support for any hook".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hooks: Option<Vec<String>>,
+    /// MountOptions is the list of the recognized mount options, e.g., "ro".
+    /// Nil value means "unknown", not "no support for any mount option".
+    /// This list does not contain filesystem-specific options passed to mount(2) syscall as (const void *).
+    #[serde(skip_serializing_if = "Option::is_none", rename = "mountOptions")]
+    mount_options: Option<Vec<String>>,
+    /// Linux is specific to Linux.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    linux: Option<Linux>,
+    /// Annotations contains implementation-specific annotation strings,
+    /// such as the implementation version, and third-party extensions.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    annotations: HashMap<String, String>,
+    /// PotentiallyUnsafeConfigAnnotations the list of the potential unsafe annotations
+    /// that may appear in `config.json`.
+    ///
+    /// A value that ends with "." is interpreted as a prefix of annotations.
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        rename = "potentiallyUnsafeConfigAnnotations",
+        default
+    )]
+    potentially_unsafe_config_annotations: Vec<String>,
+}
+
+impl Features {
+    /// OCIVersionMin is the minimum OCI Runtime Spec version recognized by the runtime, e.g., "1.0.0".
+    pub fn oci_version_min(&self) -> &str {
+        &self.oci_version_min
+    }
+
+    /// OCIVersionMax is the maximum OCI Runtime Spec version recognized by the runtime, e.g., "1.0.2-dev".
+    pub fn oci_version_max(&self) -> &str {
+        &self.oci_version_max
+    }
+
+    /// Hooks is the list of the recognized hook names, e.g., "createRuntime".
+    /// Nil value means "unknown", not "no support for any hook".
+    pub fn hooks(&self) -> Option<&[String]> {
+        self.hooks.as_deref()
+    }
+
+    /// MountOptions is the list of the recognized mount options, e.g., "ro".
+    /// Nil value means "unknown", not "no support for any mount option".
+    /// This list does not contain filesystem-specific options passed to mount(2) syscall as (const void *).
+    pub fn mount_options(&self) -> Option<&[String]> {
+        self.mount_options.as_deref()
+    }
+
+    /// Linux is specific to Linux.
+    pub fn linux(&self) -> Option<&Linux> {
+        self.linux.as_ref()
+    }
+
+    /// Annotations contains implementation-specific annotation strings,
+    /// such as the implementation version, and third-party extensions.
+    pub fn annotations(&self) -> &HashMap<String, String> {
+        &self.annotations
+    }
+
+    /// PotentiallyUnsafeConfigAnnotations the list of the potential unsafe annotations
+    /// that may appear in `config.json`.
+    ///
+    /// A value that ends with "." is interpreted as a prefix of annotations.
+    pub fn potentially_unsafe_config_annotations(&self) -> &[String] {
+        &self.potentially_unsafe_config_annotations
+    }
+
+    /// Mutable access to [`oci_version_min`](Self::oci_version_min).
+    pub fn oci_version_min_mut(&mut self) -> &mut String {
+        &mut self.oci_version_min
+    }
+
+    /// Mutable access to [`oci_version_max`](Self::oci_version_max).
+    pub fn oci_version_max_mut(&mut self) -> &mut String {
+        &mut self.oci_version_max
+    }
+
+    /// Mutable access to [`hooks`](Self::hooks).
+    pub fn hooks_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.hooks
+    }
+
+    /// Mutable access to [`mount_options`](Self::mount_options).
+    pub fn mount_options_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.mount_options
+    }
+
+    /// Mutable access to [`linux`](Self::linux).
+    pub fn linux_mut(&mut self) -> &mut Option<Linux> {
+        &mut self.linux
+    }
+
+    /// Mutable access to [`annotations`](Self::annotations).
+    pub fn annotations_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.annotations
+    }
+
+    /// Mutable access to [`potentially_unsafe_config_annotations`](Self::potentially_unsafe_config_annotations).
+    pub fn potentially_unsafe_config_annotations_mut(&mut self) -> &mut Vec<String> {
+        &mut self.potentially_unsafe_config_annotations
+    }
+}
+
+/// Linux is specific to Linux.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Linux {
+    /// Namespaces is the list of the recognized namespaces, e.g., "mount".
+    /// Nil value means "unknown", not "no support for any namespace".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    namespaces: Option<Vec<String>>,
+    /// Capabilities is the list of the recognized capabilities , e.g., "CAP_SYS_ADMIN".
+    /// Nil value means "unknown", not "no support for any capability".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capabilities: Option<Vec<String>>,
+    /// Cgroup is the cgroup driver support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cgroup: Option<Cgroup>,
+    /// Seccomp is the seccomp support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seccomp: Option<Seccomp>,
+    /// Apparmor is the AppArmor support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    apparmor: Option<Apparmor>,
+    /// Selinux is the SELinux support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selinux: Option<Selinux>,
+    /// IntelRdt is the Intel RDT support.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "intelRdt")]
+    intel_rdt: Option<IntelRdt>,
+    /// MountExtensions is the mount extensions support.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "mountExtensions")]
+    mount_extensions: Option<MountExtensions>,
+    /// NetDevices is the network devices support.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "netDevices")]
+    net_devices: Option<NetDevices>,
+}
+
+impl Linux {
+    /// Namespaces is the list of the recognized namespaces, e.g., "mount".
+    /// Nil value means "unknown", not "no support for any namespace".
+    pub fn namespaces(&self) -> Option<&[String]> {
+        self.namespaces.as_deref()
+    }
+
+    /// Capabilities is the list of the recognized capabilities , e.g., "CAP_SYS_ADMIN".
+    /// Nil value means "unknown", not "no support for any capability".
+    pub fn capabilities(&self) -> Option<&[String]> {
+        self.capabilities.as_deref()
+    }
+
+    /// Cgroup is the cgroup driver support.
+    pub fn cgroup(&self) -> Option<&Cgroup> {
+        self.cgroup.as_ref()
+    }
+
+    /// Seccomp is the seccomp support.
+    pub fn seccomp(&self) -> Option<&Seccomp> {
+        self.seccomp.as_ref()
+    }
+
+    /// Apparmor is the AppArmor support.
+    pub fn apparmor(&self) -> Option<&Apparmor> {
+        self.apparmor.as_ref()
+    }
+
+    /// Selinux is the SELinux support.
+    pub fn selinux(&self) -> Option<&Selinux> {
+        self.selinux.as_ref()
+    }
+
+    /// IntelRdt is the Intel RDT support.
+    pub fn intel_rdt(&self) -> Option<&IntelRdt> {
+        self.intel_rdt.as_ref()
+    }
+
+    /// MountExtensions is the mount extensions support.
+    pub fn mount_extensions(&self) -> Option<&MountExtensions> {
+        self.mount_extensions.as_ref()
+    }
+
+    /// NetDevices is the network devices support.
+    pub fn net_devices(&self) -> Option<&NetDevices> {
+        self.net_devices.as_ref()
+    }
+
+    /// Mutable access to [`namespaces`](Self::namespaces).
+    pub fn namespaces_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.namespaces
+    }
+
+    /// Mutable access to [`capabilities`](Self::capabilities).
+    pub fn capabilities_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.capabilities
+    }
+
+    /// Mutable access to [`cgroup`](Self::cgroup).
+    pub fn cgroup_mut(&mut self) -> &mut Option<Cgroup> {
+        &mut self.cgroup
+    }
+
+    /// Mutable access to [`seccomp`](Self::seccomp).
+    pub fn seccomp_mut(&mut self) -> &mut Option<Seccomp> {
+        &mut self.seccomp
+    }
+
+    /// Mutable access to [`apparmor`](Self::apparmor).
+    pub fn apparmor_mut(&mut self) -> &mut Option<Apparmor> {
+        &mut self.apparmor
+    }
+
+    /// Mutable access to [`selinux`](Self::selinux).
+    pub fn selinux_mut(&mut self) -> &mut Option<Selinux> {
+        &mut self.selinux
+    }
+
+    /// Mutable access to [`intel_rdt`](Self::intel_rdt).
+    pub fn intel_rdt_mut(&mut self) -> &mut Option<IntelRdt> {
+        &mut self.intel_rdt
+    }
+
+    /// Mutable access to [`mount_extensions`](Self::mount_extensions).
+    pub fn mount_extensions_mut(&mut self) -> &mut Option<MountExtensions> {
+        &mut self.mount_extensions
+    }
+
+    /// Mutable access to [`net_devices`](Self::net_devices).
+    pub fn net_devices_mut(&mut self) -> &mut Option<NetDevices> {
+        &mut self.net_devices
+    }
+}
+
+/// Cgroup represents the "cgroup" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Cgroup {
+    /// V1 represents whether Cgroup v1 support is compiled in.
+    /// Unrelated to whether the host uses cgroup v1 or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    v1: Option<bool>,
+    /// V2 represents whether Cgroup v2 support is compiled in.
+    /// Unrelated to whether the host uses cgroup v2 or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    v2: Option<bool>,
+    /// Systemd represents whether systemd-cgroup support is compiled in.
+    /// Unrelated to whether the host uses systemd or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    systemd: Option<bool>,
+    /// SystemdUser represents whether user-scoped systemd-cgroup support is compiled in.
+    /// Unrelated to whether the host uses systemd or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none", rename = "systemdUser")]
+    systemd_user: Option<bool>,
+    /// Rdma represents whether RDMA cgroup support is compiled in.
+    /// Unrelated to whether the host supports RDMA or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rdma: Option<bool>,
+}
+
+impl Cgroup {
+    /// V1 represents whether Cgroup v1 support is compiled in.
+    /// Unrelated to whether the host uses cgroup v1 or not.
+    /// Nil value means "unknown", not "false".
+    pub fn v1(&self) -> Option<bool> {
+        self.v1
+    }
+
+    /// V2 represents whether Cgroup v2 support is compiled in.
+    /// Unrelated to whether the host uses cgroup v2 or not.
+    /// Nil value means "unknown", not "false".
+    pub fn v2(&self) -> Option<bool> {
+        self.v2
+    }
+
+    /// Systemd represents whether systemd-cgroup support is compiled in.
+    /// Unrelated to whether the host uses systemd or not.
+    /// Nil value means "unknown", not "false".
+    pub fn systemd(&self) -> Option<bool> {
+        self.systemd
+    }
+
+    /// SystemdUser represents whether user-scoped systemd-cgroup support is compiled in.
+    /// Unrelated to whether the host uses systemd or not.
+    /// Nil value means "unknown", not "false".
+    pub fn systemd_user(&self) -> Option<bool> {
+        self.systemd_user
+    }
+
+    /// Rdma represents whether RDMA cgroup support is compiled in.
+    /// Unrelated to whether the host supports RDMA or not.
+    /// Nil value means "unknown", not "false".
+    pub fn rdma(&self) -> Option<bool> {
+        self.rdma
+    }
+
+    /// Mutable access to [`v1`](Self::v1).
+    pub fn v1_mut(&mut self) -> &mut Option<bool> {
+        &mut self.v1
+    }
+
+    /// Mutable access to [`v2`](Self::v2).
+    pub fn v2_mut(&mut self) -> &mut Option<bool> {
+        &mut self.v2
+    }
+
+    /// Mutable access to [`systemd`](Self::systemd).
+    pub fn systemd_mut(&mut self) -> &mut Option<bool> {
+        &mut self.systemd
+    }
+
+    /// Mutable access to [`systemd_user`](Self::systemd_user).
+    pub fn systemd_user_mut(&mut self) -> &mut Option<bool> {
+        &mut self.systemd_user
+    }
+
+    /// Mutable access to [`rdma`](Self::rdma).
+    pub fn rdma_mut(&mut self) -> &mut Option<bool> {
+        &mut self.rdma
+    }
+}
+
+/// Seccomp represents the "seccomp" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Seccomp {
+    /// Enabled is true if seccomp support is compiled in.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    /// Actions is the list of the recognized actions, e.g., "SCMP_ACT_NOTIFY".
+    /// Nil value means "unknown", not "no support for any action".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actions: Option<Vec<String>>,
+    /// Operators is the list of the recognized operators, e.g., "SCMP_CMP_NE".
+    /// Nil value means "unknown", not "no support for any operator".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operators: Option<Vec<String>>,
+    /// Archs is the list of the recognized archs, e.g., "SCMP_ARCH_X86_64".
+    /// Nil value means "unknown", not "no support for any arch".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archs: Option<Vec<String>>,
+    /// KnownFlags is the list of the recognized filter flags, e.g., "SECCOMP_FILTER_FLAG_LOG".
+    /// Nil value means "unknown", not "no flags are recognized".
+    #[serde(skip_serializing_if = "Option::is_none", rename = "knownFlags")]
+    known_flags: Option<Vec<String>>,
+    /// SupportedFlags is the list of the supported filter flags, e.g., "SECCOMP_FILTER_FLAG_LOG".
+    /// This list may be a subset of KnownFlags due to some flags
+    /// not supported by the current kernel and/or libseccomp.
+    /// Nil value means "unknown", not "no flags are supported".
+    #[serde(skip_serializing_if = "Option::is_none", rename = "supportedFlags")]
+    supported_flags: Option<Vec<String>>,
+}
+
+impl Seccomp {
+    /// Enabled is true if seccomp support is compiled in.
+    /// Nil value means "unknown", not "false".
+    pub fn enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// Actions is the list of the recognized actions, e.g., "SCMP_ACT_NOTIFY".
+    /// Nil value means "unknown", not "no support for any action".
+    pub fn actions(&self) -> Option<&[String]> {
+        self.actions.as_deref()
+    }
+
+    /// Operators is the list of the recognized operators, e.g., "SCMP_CMP_NE".
+    /// Nil value means "unknown", not "no support for any operator".
+    pub fn operators(&self) -> Option<&[String]> {
+        self.operators.as_deref()
+    }
+
+    /// Archs is the list of the recognized archs, e.g., "SCMP_ARCH_X86_64".
+    /// Nil value means "unknown", not "no support for any arch".
+    pub fn archs(&self) -> Option<&[String]> {
+        self.archs.as_deref()
+    }
+
+    /// KnownFlags is the list of the recognized filter flags, e.g., "SECCOMP_FILTER_FLAG_LOG".
+    /// Nil value means "unknown", not "no flags are recognized".
+    pub fn known_flags(&self) -> Option<&[String]> {
+        self.known_flags.as_deref()
+    }
+
+    /// SupportedFlags is the list of the supported filter flags, e.g., "SECCOMP_FILTER_FLAG_LOG".
+    /// This list may be a subset of KnownFlags due to some flags
+    /// not supported by the current kernel and/or libseccomp.
+    /// Nil value means "unknown", not "no flags are supported".
+    pub fn supported_flags(&self) -> Option<&[String]> {
+        self.supported_flags.as_deref()
+    }
+
+    /// Mutable access to [`enabled`](Self::enabled).
+    pub fn enabled_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enabled
+    }
+
+    /// Mutable access to [`actions`](Self::actions).
+    pub fn actions_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.actions
+    }
+
+    /// Mutable access to [`operators`](Self::operators).
+    pub fn operators_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.operators
+    }
+
+    /// Mutable access to [`archs`](Self::archs).
+    pub fn archs_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.archs
+    }
+
+    /// Mutable access to [`known_flags`](Self::known_flags).
+    pub fn known_flags_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.known_flags
+    }
+
+    /// Mutable access to [`supported_flags`](Self::supported_flags).
+    pub fn supported_flags_mut(&mut self) -> &mut Option<Vec<String>> {
+        &mut self.supported_flags
+    }
+}
+
+/// Apparmor represents the "apparmor" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Apparmor {
+    /// Enabled is true if AppArmor support is compiled in.
+    /// Unrelated to whether the host supports AppArmor or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+impl Apparmor {
+    /// Enabled is true if AppArmor support is compiled in.
+    /// Unrelated to whether the host supports AppArmor or not.
+    /// Nil value means "unknown", not "false".
+    pub fn enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// Mutable access to [`enabled`](Self::enabled).
+    pub fn enabled_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enabled
+    }
+}
+
+/// Selinux represents the "selinux" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Selinux {
+    /// Enabled is true if SELinux support is compiled in.
+    /// Unrelated to whether the host supports SELinux or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+impl Selinux {
+    /// Enabled is true if SELinux support is compiled in.
+    /// Unrelated to whether the host supports SELinux or not.
+    /// Nil value means "unknown", not "false".
+    pub fn enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// Mutable access to [`enabled`](Self::enabled).
+    pub fn enabled_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enabled
+    }
+}
+
+/// IntelRdt represents the "intelRdt" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct IntelRdt {
+    /// Enabled is true if Intel RDT support is compiled in.
+    /// Unrelated to whether the host supports Intel RDT or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+impl IntelRdt {
+    /// Enabled is true if Intel RDT support is compiled in.
+    /// Unrelated to whether the host supports Intel RDT or not.
+    /// Nil value means "unknown", not "false".
+    pub fn enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// Mutable access to [`enabled`](Self::enabled).
+    pub fn enabled_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enabled
+    }
+}
+
+/// MountExtensions represents the "mountExtensions" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct MountExtensions {
+    /// IDMap represents the status of idmap mounts support.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    idmap: Option<IDMap>,
+}
+
+impl MountExtensions {
+    /// IDMap represents the status of idmap mounts support.
+    pub fn idmap(&self) -> Option<&IDMap> {
+        self.idmap.as_ref()
+    }
+
+    /// Mutable access to [`idmap`](Self::idmap).
+    pub fn idmap_mut(&mut self) -> &mut Option<IDMap> {
+        &mut self.idmap
+    }
+}
+
+/// IDMap represents the "idmap" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct IDMap {
+    /// Enabled represents whether idmap mounts supports is compiled in.
+    /// Unrelated to whether the host supports it or not.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+impl IDMap {
+    /// Enabled represents whether idmap mounts supports is compiled in.
+    /// Unrelated to whether the host supports it or not.
+    /// Nil value means "unknown", not "false".
+    pub fn enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// Mutable access to [`enabled`](Self::enabled).
+    pub fn enabled_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enabled
+    }
+}
+
+/// NetDevices represents the "netDevices" field.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct NetDevices {
+    /// Enabled is true if network devices support is compiled in.
+    /// Nil value means "unknown", not "false".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+impl NetDevices {
+    /// Enabled is true if network devices support is compiled in.
+    /// Nil value means "unknown", not "false".
+    pub fn enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// Mutable access to [`enabled`](Self::enabled).
+    pub fn enabled_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enabled
+    }
+}
+
+impl_to_builder! {
+    Features => FeaturesBuilder {
+        oci_version_min, oci_version_max, hooks, mount_options, linux, annotations,
+        potentially_unsafe_config_annotations,
+    },
+    Linux => LinuxBuilder {
+        namespaces, capabilities, cgroup, seccomp, apparmor, selinux, intel_rdt,
+        mount_extensions, net_devices,
+    },
+    Cgroup => CgroupBuilder { v1, v2, systemd, systemd_user, rdma },
+    Seccomp => SeccompBuilder { enabled, actions, operators, archs, known_flags, supported_flags },
+    Apparmor => ApparmorBuilder { enabled },
+    Selinux => SelinuxBuilder { enabled },
+    IntelRdt => IntelRdtBuilder { enabled },
+    MountExtensions => MountExtensionsBuilder { idmap },
+    IDMap => IDMapBuilder { enabled },
+    NetDevices => NetDevicesBuilder { enabled },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Arch, Capability, LinuxNamespaceType, LinuxSeccompAction, LinuxSeccompFlag};
+    use crate::{LinuxSeccompOperator, OCI_VERSION};
+    use serde_json::Value;
+
+    /// Output of `runc features` (runc 1.3, runtime-spec 1.2.1).
+    const RUNC_FEATURES: &str = include_str!("../testdata/runc-features.json");
+
+    #[test]
+    fn runc_features_round_trip() {
+        let reference: Value = serde_json::from_str(RUNC_FEATURES).unwrap();
+        let features: Features = serde_json::from_str(RUNC_FEATURES).unwrap();
+        assert_eq!(serde_json::to_value(&features).unwrap(), reference);
+
+        assert_eq!(features.oci_version_max(), OCI_VERSION);
+        assert_eq!(
+            features.hooks().unwrap(),
+            [
+                "prestart",
+                "createRuntime",
+                "createContainer",
+                "startContainer",
+                "poststart",
+                "poststop",
+            ]
+        );
+        assert_eq!(
+            features.annotations()["org.opencontainers.runc.version"],
+            "1.3.0"
+        );
+        let linux = features.linux().unwrap();
+        assert_eq!(linux.cgroup().unwrap().v2(), Some(true));
+        assert_eq!(
+            linux.mount_extensions().unwrap().idmap().unwrap().enabled(),
+            Some(true)
+        );
+        assert_eq!(linux.net_devices().unwrap().enabled(), Some(true));
+    }
+
+    #[test]
+    fn runc_features_use_known_names() {
+        let features: Features = serde_json::from_str(RUNC_FEATURES).unwrap();
+        let linux = features.linux().unwrap();
+        for name in linux.namespaces().unwrap() {
+            name.parse::<LinuxNamespaceType>().unwrap();
+        }
+        for name in linux.capabilities().unwrap() {
+            name.parse::<Capability>().unwrap();
+        }
+        let seccomp = linux.seccomp().unwrap();
+        for name in seccomp.actions().unwrap() {
+            name.parse::<LinuxSeccompAction>().unwrap();
+        }
+        for name in seccomp.operators().unwrap() {
+            name.parse::<LinuxSeccompOperator>().unwrap();
+        }
+        for name in seccomp.archs().unwrap() {
+            name.parse::<Arch>().unwrap();
+        }
+        for name in seccomp.known_flags().unwrap() {
+            name.parse::<LinuxSeccompFlag>().unwrap();
+        }
+    }
+}
@@ -0,0 +1,259 @@
+/*
+ * Copyright 2020 fsyncd, Berlin, Germany.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::{
+    Linux, LinuxCapabilities, LinuxDeviceCgroup, LinuxIDMapping, LinuxNamespace,
+    LinuxNamespaceType, LinuxResources, Mount, POSIXRlimit, Process, Root, Spec, OCI_VERSION,
+};
+use std::path::Path;
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+fn mount(destination: &str, mount_type: &str, source: &str, options: &[&str]) -> Mount {
+    Mount {
+        destination: destination.to_string(),
+        mount_type: Some(mount_type.to_string()),
+        source: Some(source.to_string()),
+        options: strings(options),
+        ..Mount::default()
+    }
+}
+
+impl Spec {
+    /// Returns the baseline Linux configuration generated by `runc spec`: an interactive `sh`
+    /// in a read-only `rootfs`, the standard pseudo-filesystem mounts, a minimal capability
+    /// set, masked and read-only kernel paths, and new pid, network, ipc, uts, mount and
+    /// cgroup namespaces.
+    pub fn default_linux() -> Spec {
+        let capabilities = strings(&["CAP_AUDIT_WRITE", "CAP_KILL", "CAP_NET_BIND_SERVICE"]);
+        Spec {
+            version: OCI_VERSION.to_string(),
+            process: Some(Process {
+                terminal: Some(true),
+                args: strings(&["sh"]),
+                env: strings(&[
+                    "PATH=/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin",
+                    "TERM=xterm",
+                ]),
+                cwd: "/".to_string(),
+                capabilities: Some(LinuxCapabilities {
+                    bounding: capabilities.clone(),
+                    effective: capabilities.clone(),
+                    permitted: capabilities,
+                    ..LinuxCapabilities::default()
+                }),
+                rlimits: vec![POSIXRlimit {
+                    rlimit_type: "RLIMIT_NOFILE".to_string(),
+                    hard: 1024,
+                    soft: 1024,
+                }],
+                no_new_privileges: Some(true),
+                ..Process::default()
+            }),
+            root: Some(Root {
+                path: "rootfs".to_string(),
+                readonly: Some(true),
+            }),
+            hostname: Some("runc".to_string()),
+            mounts: vec![
+                mount("/proc", "proc", "proc", &[]),
+                mount(
+                    "/dev",
+                    "tmpfs",
+                    "tmpfs",
+                    &["nosuid", "strictatime", "mode=755", "size=65536k"],
+                ),
+                mount(
+                    "/dev/pts",
+                    "devpts",
+                    "devpts",
+                    &[
+                        "nosuid",
+                        "noexec",
+                        "newinstance",
+                        "ptmxmode=0666",
+                        "mode=0620",
+                        "gid=5",
+                    ],
+                ),
+                mount(
+                    "/dev/shm",
+                    "tmpfs",
+                    "shm",
+                    &["nosuid", "noexec", "nodev", "mode=1777", "size=65536k"],
+                ),
+                mount(
+                    "/dev/mqueue",
+                    "mqueue",
+                    "mqueue",
+                    &["nosuid", "noexec", "nodev"],
+                ),
+                mount(
+                    "/sys",
+                    "sysfs",
+                    "sysfs",
+                    &["nosuid", "noexec", "nodev", "ro"],
+                ),
+                mount(
+                    "/sys/fs/cgroup",
+                    "cgroup",
+                    "cgroup",
+                    &["nosuid", "noexec", "nodev", "relatime", "ro"],
+                ),
+            ],
+            linux: Some(Linux {
+                resources: Some(LinuxResources {
+                    devices: vec![LinuxDeviceCgroup {
+                        allow: false,
+                        access: Some("rwm".to_string()),
+                        ..LinuxDeviceCgroup::default()
+                    }],
+                    ..LinuxResources::default()
+                }),
+                namespaces: [
+                    LinuxNamespaceType::Pid,
+                    LinuxNamespaceType::Network,
+                    LinuxNamespaceType::Ipc,
+                    LinuxNamespaceType::Uts,
+                    LinuxNamespaceType::Mount,
+                    LinuxNamespaceType::Cgroup,
+                ]
+                .iter()
+                .map(|&namespace_type| LinuxNamespace {
+                    namespace_type,
+                    path: None,
+                })
+                .collect(),
+                masked_paths: strings(&[
+                    "/proc/acpi",
+                    "/proc/asound",
+                    "/proc/kcore",
+                    "/proc/keys",
+                    "/proc/latency_stats",
+                    "/proc/timer_list",
+                    "/proc/timer_stats",
+                    "/proc/sched_debug",
+                    "/sys/firmware",
+                    "/proc/scsi",
+                ]),
+                readonly_paths: strings(&[
+                    "/proc/bus",
+                    "/proc/fs",
+                    "/proc/irq",
+                    "/proc/sys",
+                    "/proc/sysrq-trigger",
+                ]),
+                ..Linux::default()
+            }),
+            ..Spec::default()
+        }
+    }
+    /// Returns a copy of the spec adjusted to run without root privileges, the same way
+    /// `runc spec --rootless` does: the network namespace is dropped, a user namespace is
+    /// added mapping container root to the host `uid` and `gid`, `/sys` becomes a read-only
+    /// recursive bind mount, `uid=` and `gid=` mount options are removed and cgroup
+    /// resources are cleared. Network devices are cleared along with the network namespace
+    /// they would be moved into.
+    pub fn to_rootless(&self, uid: u32, gid: u32) -> Spec {
+        let mut spec = self.clone();
+        let linux = spec.linux.get_or_insert_with(Linux::default);
+        linux.namespaces.retain(|ns| {
+            ns.namespace_type != LinuxNamespaceType::Network
+                && ns.namespace_type != LinuxNamespaceType::User
+        });
+        linux.namespaces.push(LinuxNamespace {
+            namespace_type: LinuxNamespaceType::User,
+            path: None,
+        });
+        linux.uid_mappings = vec![LinuxIDMapping {
+            container_id: 0,
+            host_id: uid,
+            size: 1,
+        }];
+        linux.gid_mappings = vec![LinuxIDMapping {
+            container_id: 0,
+            host_id: gid,
+            size: 1,
+        }];
+        linux.resources = None;
+        linux.net_devices.clear();
+
+        for mount in spec.mounts.iter_mut() {
+            if Path::new(&mount.destination)
+                .components()
+                .eq(Path::new("/sys").components())
+            {
+                *mount = Mount {
+                    destination: "/sys".to_string(),
+                    mount_type: Some("none".to_string()),
+                    source: Some("/sys".to_string()),
+                    options: strings(&["rbind", "nosuid", "noexec", "nodev", "ro"]),
+                    ..Mount::default()
+                };
+                continue;
+            }
+            mount
+                .options
+                .retain(|option| !option.starts_with("gid=") && !option.starts_with("uid="));
+        }
+        spec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinuxNetDevice;
+    use serde_json::Value;
+
+    /// Output of `runc spec` (runc 1.3, runtime-spec 1.2.1).
+    const RUNC_SPEC: &str = include_str!("../testdata/runc-spec.json");
+
+    #[test]
+    fn default_linux_matches_runc_spec() {
+        let reference: Value = serde_json::from_str(RUNC_SPEC).unwrap();
+        let spec = Spec::default_linux();
+        assert_eq!(serde_json::to_value(&spec).unwrap(), reference);
+        assert_eq!(spec.version(), OCI_VERSION);
+    }
+
+    #[test]
+    fn to_rootless_is_valid() {
+        let mut spec = Spec::default_linux();
+        let linux = spec.linux_mut().as_mut().unwrap();
+        linux
+            .net_devices_mut()
+            .insert("eth1".to_string(), LinuxNetDevice::default());
+        assert!(spec.validate().is_ok());
+
+        let rootless = spec.to_rootless(1000, 1000);
+        assert!(rootless.validate().is_ok());
+        let linux = rootless.linux().unwrap();
+        assert!(linux.net_devices().is_empty());
+        assert!(!linux.has_namespace(LinuxNamespaceType::Network));
+        assert!(linux.has_namespace(LinuxNamespaceType::User));
+        assert!(linux.resources().is_none());
+    }
+
+    #[test]
+    fn runc_spec_round_trips() {
+        let reference: Value = serde_json::from_str(RUNC_SPEC).unwrap();
+        let spec: Spec = serde_json::from_str(RUNC_SPEC).unwrap();
+        assert_eq!(serde_json::to_value(&spec).unwrap(), reference);
+    }
+}
@@ -17,10 +17,127 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 #[macro_use]
 extern crate derive_builder;
 
+/// Implements `From<T> for TBuilder` and `T::to_builder()` so that an existing value
+/// can be turned back into a pre-filled builder.
+macro_rules! impl_to_builder {
+    ($($ty:ident => $builder:ident { $($field:ident),* $(,)? },)*) => {
+        $(
+            impl From<$ty> for $builder {
+                fn from(value: $ty) -> Self {
+                    $builder {
+                        $($field: Some(value.$field),)*
+                    }
+                }
+            }
+
+            impl $ty {
+                /// Returns a builder pre-filled with the values of this instance.
+                pub fn to_builder(&self) -> $builder {
+                    self.clone().into()
+                }
+            }
+        )*
+    };
+}
+
+/// Declares an enum of fixed specification strings, with serde, `Display` and `FromStr`
+/// all using the exact spelling from the runtime specification.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum $name {
+            $($(#[$variant_meta])* #[serde(rename = $value)] $variant,)*
+        }
+
+        impl $name {
+            /// Returns the spelling used by the runtime specification.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, ParseError> {
+                match s {
+                    $($value => Ok($name::$variant),)*
+                    _ => Err(ParseError::new(stringify!($name), s)),
+                }
+            }
+        }
+    };
+}
+
+mod bundle;
+mod capability;
+pub mod features;
+mod generate;
+mod state;
+mod validate;
+
+pub use bundle::{Bundle, BundleError, CONFIG_FILE};
+pub use capability::{Capability, CapabilitySet};
+pub use features::{Features, FeaturesBuilder, FeaturesBuilderError};
+pub use state::{ContainerStatus, State, StateBuilder, StateBuilderError};
+pub use validate::{ValidationError, ValidationErrorKind};
+
+/// Version of the Open Container Initiative Runtime Specification implemented by this crate.
+pub const OCI_VERSION: &str = "1.2.1";
+
+/// ParseError is returned when a string does not name a known value of a specification enum.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    kind: &'static str,
+    value: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: &'static str, value: &str) -> Self {
+        ParseError {
+            kind,
+            value: value.to_string(),
+        }
+    }
+
+    /// Kind is the name of the type that failed to parse.
+    pub fn kind(&self) -> &str {
+        self.kind
+    }
+
+    /// Value is the string that was rejected.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} {:?}", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// Spec is the base configuration for the container.
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -37,8 +154,11 @@ pub struct Spec {
     /// Hostname configures the container's hostname.
     #[serde(skip_serializing_if = "Option::is_none")]
     hostname: Option<String>,
+    /// Domainname configures the container's NIS domain name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domainname: Option<String>,
     /// Mounts configures additional mounts (on top of Root).
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     mounts: Vec<Mount>,
     /// Hooks configures callbacks for container lifecycle events.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,6 +169,163 @@ pub struct Spec {
     /// Linux is platform-specific configuration for Linux based containers.
     #[serde(skip_serializing_if = "Option::is_none")]
     linux: Option<Linux>,
+    /// Solaris is platform-specific configuration for Solaris based containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solaris: Option<Solaris>,
+    /// Windows is platform-specific configuration for Windows based containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    windows: Option<Windows>,
+    /// VM specifies configuration for virtual-machine-based containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vm: Option<VM>,
+    /// ZOS is platform-specific configuration for z/OS based containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zos: Option<ZOS>,
+    /// FreeBSD is platform-specific configuration for FreeBSD based containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    freebsd: Option<FreeBSD>,
+}
+
+impl Spec {
+    /// Version of the Open Container Initiative Runtime Specification with which the bundle complies.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Process configures the container process.
+    pub fn process(&self) -> Option<&Process> {
+        self.process.as_ref()
+    }
+
+    /// Root configures the container's root filesystem.
+    pub fn root(&self) -> Option<&Root> {
+        self.root.as_ref()
+    }
+
+    /// Hostname configures the container's hostname.
+    pub fn hostname(&self) -> Option<&str> {
+        self.hostname.as_deref()
+    }
+
+    /// Mounts configures additional mounts (on top of Root).
+    pub fn mounts(&self) -> &[Mount] {
+        &self.mounts
+    }
+
+    /// Hooks configures callbacks for container lifecycle events.
+    pub fn hooks(&self) -> Option<&Hooks> {
+        self.hooks.as_ref()
+    }
+
+    /// Annotations contains arbitrary metadata for the container.
+    pub fn annotations(&self) -> &HashMap<String, String> {
+        &self.annotations
+    }
+
+    /// Linux is platform-specific configuration for Linux based containers.
+    pub fn linux(&self) -> Option<&Linux> {
+        self.linux.as_ref()
+    }
+
+    /// Windows is platform-specific configuration for Windows based containers.
+    pub fn windows(&self) -> Option<&Windows> {
+        self.windows.as_ref()
+    }
+
+    /// VM specifies configuration for virtual-machine-based containers.
+    pub fn vm(&self) -> Option<&VM> {
+        self.vm.as_ref()
+    }
+
+    /// Solaris is platform-specific configuration for Solaris based containers.
+    pub fn solaris(&self) -> Option<&Solaris> {
+        self.solaris.as_ref()
+    }
+
+    /// ZOS is platform-specific configuration for z/OS based containers.
+    pub fn zos(&self) -> Option<&ZOS> {
+        self.zos.as_ref()
+    }
+
+    /// FreeBSD is platform-specific configuration for FreeBSD based containers.
+    pub fn freebsd(&self) -> Option<&FreeBSD> {
+        self.freebsd.as_ref()
+    }
+
+    /// Domainname configures the container's NIS domain name.
+    pub fn domainname(&self) -> Option<&str> {
+        self.domainname.as_deref()
+    }
+
+    /// Mutable access to [`version`](Self::version).
+    pub fn version_mut(&mut self) -> &mut String {
+        &mut self.version
+    }
+
+    /// Mutable access to [`process`](Self::process).
+    pub fn process_mut(&mut self) -> &mut Option<Process> {
+        &mut self.process
+    }
+
+    /// Mutable access to [`root`](Self::root).
+    pub fn root_mut(&mut self) -> &mut Option<Root> {
+        &mut self.root
+    }
+
+    /// Mutable access to [`hostname`](Self::hostname).
+    pub fn hostname_mut(&mut self) -> &mut Option<String> {
+        &mut self.hostname
+    }
+
+    /// Mutable access to [`mounts`](Self::mounts).
+    pub fn mounts_mut(&mut self) -> &mut Vec<Mount> {
+        &mut self.mounts
+    }
+
+    /// Mutable access to [`hooks`](Self::hooks).
+    pub fn hooks_mut(&mut self) -> &mut Option<Hooks> {
+        &mut self.hooks
+    }
+
+    /// Mutable access to [`annotations`](Self::annotations).
+    pub fn annotations_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.annotations
+    }
+
+    /// Mutable access to [`linux`](Self::linux).
+    pub fn linux_mut(&mut self) -> &mut Option<Linux> {
+        &mut self.linux
+    }
+
+    /// Mutable access to [`windows`](Self::windows).
+    pub fn windows_mut(&mut self) -> &mut Option<Windows> {
+        &mut self.windows
+    }
+
+    /// Mutable access to [`vm`](Self::vm).
+    pub fn vm_mut(&mut self) -> &mut Option<VM> {
+        &mut self.vm
+    }
+
+    /// Mutable access to [`solaris`](Self::solaris).
+    pub fn solaris_mut(&mut self) -> &mut Option<Solaris> {
+        &mut self.solaris
+    }
+
+    /// Mutable access to [`zos`](Self::zos).
+    pub fn zos_mut(&mut self) -> &mut Option<ZOS> {
+        &mut self.zos
+    }
+
+    /// Mutable access to [`freebsd`](Self::freebsd).
+    pub fn freebsd_mut(&mut self) -> &mut Option<FreeBSD> {
+        &mut self.freebsd
+    }
+
+    /// Mutable access to [`domainname`](Self::domainname).
+    pub fn domainname_mut(&mut self) -> &mut Option<String> {
+        &mut self.domainname
+    }
 }
 
 /// Process contains information to start a specific application inside the container.
@@ -58,16 +335,21 @@ pub struct Process {
     /// Terminal creates an interactive terminal for the container.
     #[serde(skip_serializing_if = "Option::is_none")]
     terminal: Option<bool>,
-    /// ConsoleSize specifies the size of the console.
+    /// ConsoleSize specifies the size of the console in characters.
+    /// It is ignored unless Terminal is set.
     #[serde(skip_serializing_if = "Option::is_none", rename = "consoleSize")]
     console_size: Option<ConsoleSizeBox>,
     /// User specifies user information for the process.
     user: User,
     /// Args specifies the binary and arguments for the application to execute.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     args: Vec<String>,
+    /// CommandLine specifies the full command line for the application to execute on Windows.
+    /// When set, it is used instead of escaping and joining Args.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "commandLine")]
+    command_line: Option<String>,
     /// Env populates the process environment for the process.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     env: Vec<String>,
     /// Cwd is the current working directory for the process and must be
     /// relative to the container's root.
@@ -87,9 +369,203 @@ pub struct Process {
     /// Specify an oom_score_adj for the container.
     #[serde(skip_serializing_if = "Option::is_none", rename = "oomScoreAdj")]
     oom_score_adj: Option<i32>,
+    /// Scheduler specifies the scheduling attributes for a process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduler: Option<Scheduler>,
     /// SelinuxLabel specifies the selinux context that the container process is run as.
     #[serde(skip_serializing_if = "Option::is_none", rename = "selinuxLabel")]
     selinux_label: Option<String>,
+    /// IOPriority contains the I/O priority settings for the process.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ioPriority")]
+    io_priority: Option<LinuxIOPriority>,
+    /// ExecCPUAffinity specifies CPU affinity for exec processes.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "execCPUAffinity")]
+    exec_cpu_affinity: Option<CPUAffinity>,
+}
+
+impl Process {
+    /// Terminal creates an interactive terminal for the container.
+    pub fn terminal(&self) -> Option<bool> {
+        self.terminal
+    }
+
+    /// ConsoleSize specifies the size of the console in characters.
+    /// It is ignored unless Terminal is set.
+    pub fn console_size(&self) -> Option<&ConsoleSizeBox> {
+        self.console_size.as_ref()
+    }
+
+    /// User specifies user information for the process.
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Args specifies the binary and arguments for the application to execute.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Env populates the process environment for the process.
+    pub fn env(&self) -> &[String] {
+        &self.env
+    }
+
+    /// Cwd is the current working directory for the process and must be
+    /// relative to the container's root.
+    pub fn cwd(&self) -> &str {
+        &self.cwd
+    }
+
+    /// Capabilities are Linux capabilities that are kept for the process.
+    pub fn capabilities(&self) -> Option<&LinuxCapabilities> {
+        self.capabilities.as_ref()
+    }
+
+    /// Rlimits specifies rlimit options to apply to the process.
+    pub fn rlimits(&self) -> &[POSIXRlimit] {
+        &self.rlimits
+    }
+
+    /// NoNewPrivileges controls whether additional privileges could be gained by processes in the container.
+    pub fn no_new_privileges(&self) -> Option<bool> {
+        self.no_new_privileges
+    }
+
+    /// ApparmorProfile specifies the apparmor profile for the container.
+    pub fn app_armor_profile(&self) -> Option<&str> {
+        self.app_armor_profile.as_deref()
+    }
+
+    /// Specify an oom_score_adj for the container.
+    pub fn oom_score_adj(&self) -> Option<i32> {
+        self.oom_score_adj
+    }
+
+    /// SelinuxLabel specifies the selinux context that the container process is run as.
+    pub fn selinux_label(&self) -> Option<&str> {
+        self.selinux_label.as_deref()
+    }
+
+    /// CommandLine specifies the full command line for the application to execute on Windows.
+    /// When set, it is used instead of escaping and joining Args.
+    pub fn command_line(&self) -> Option<&str> {
+        self.command_line.as_deref()
+    }
+
+    /// Scheduler specifies the scheduling attributes for a process.
+    pub fn scheduler(&self) -> Option<&Scheduler> {
+        self.scheduler.as_ref()
+    }
+
+    /// IOPriority contains the I/O priority settings for the process.
+    pub fn io_priority(&self) -> Option<&LinuxIOPriority> {
+        self.io_priority.as_ref()
+    }
+
+    /// ExecCPUAffinity specifies CPU affinity for exec processes.
+    pub fn exec_cpu_affinity(&self) -> Option<&CPUAffinity> {
+        self.exec_cpu_affinity.as_ref()
+    }
+
+    /// Mutable access to [`terminal`](Self::terminal).
+    pub fn terminal_mut(&mut self) -> &mut Option<bool> {
+        &mut self.terminal
+    }
+
+    /// Mutable access to [`console_size`](Self::console_size).
+    pub fn console_size_mut(&mut self) -> &mut Option<ConsoleSizeBox> {
+        &mut self.console_size
+    }
+
+    /// Mutable access to [`user`](Self::user).
+    pub fn user_mut(&mut self) -> &mut User {
+        &mut self.user
+    }
+
+    /// Mutable access to [`args`](Self::args).
+    pub fn args_mut(&mut self) -> &mut Vec<String> {
+        &mut self.args
+    }
+
+    /// Mutable access to [`env`](Self::env).
+    pub fn env_mut(&mut self) -> &mut Vec<String> {
+        &mut self.env
+    }
+
+    /// Mutable access to [`cwd`](Self::cwd).
+    pub fn cwd_mut(&mut self) -> &mut String {
+        &mut self.cwd
+    }
+
+    /// Mutable access to [`capabilities`](Self::capabilities).
+    pub fn capabilities_mut(&mut self) -> &mut Option<LinuxCapabilities> {
+        &mut self.capabilities
+    }
+
+    /// Mutable access to [`rlimits`](Self::rlimits).
+    pub fn rlimits_mut(&mut self) -> &mut Vec<POSIXRlimit> {
+        &mut self.rlimits
+    }
+
+    /// Mutable access to [`no_new_privileges`](Self::no_new_privileges).
+    pub fn no_new_privileges_mut(&mut self) -> &mut Option<bool> {
+        &mut self.no_new_privileges
+    }
+
+    /// Mutable access to [`app_armor_profile`](Self::app_armor_profile).
+    pub fn app_armor_profile_mut(&mut self) -> &mut Option<String> {
+        &mut self.app_armor_profile
+    }
+
+    /// Mutable access to [`oom_score_adj`](Self::oom_score_adj).
+    pub fn oom_score_adj_mut(&mut self) -> &mut Option<i32> {
+        &mut self.oom_score_adj
+    }
+
+    /// Mutable access to [`selinux_label`](Self::selinux_label).
+    pub fn selinux_label_mut(&mut self) -> &mut Option<String> {
+        &mut self.selinux_label
+    }
+
+    /// Mutable access to [`command_line`](Self::command_line).
+    pub fn command_line_mut(&mut self) -> &mut Option<String> {
+        &mut self.command_line
+    }
+
+    /// Mutable access to [`scheduler`](Self::scheduler).
+    pub fn scheduler_mut(&mut self) -> &mut Option<Scheduler> {
+        &mut self.scheduler
+    }
+
+    /// Mutable access to [`io_priority`](Self::io_priority).
+    pub fn io_priority_mut(&mut self) -> &mut Option<LinuxIOPriority> {
+        &mut self.io_priority
+    }
+
+    /// Mutable access to [`exec_cpu_affinity`](Self::exec_cpu_affinity).
+    pub fn exec_cpu_affinity_mut(&mut self) -> &mut Option<CPUAffinity> {
+        &mut self.exec_cpu_affinity
+    }
+
+    /// Sets the environment variable `key` to `value`, replacing any existing entry for `key`.
+    pub fn set_env(&mut self, key: &str, value: &str) {
+        let entry = format!("{}={}", key, value);
+        match self.env.iter_mut().find(|e| env_key(e) == key) {
+            Some(existing) => *existing = entry,
+            None => self.env.push(entry),
+        }
+    }
+
+    /// Removes the environment variable `key`, returning whether it was present.
+    pub fn remove_env(&mut self, key: &str) -> bool {
+        let len = self.env.len();
+        self.env.retain(|e| env_key(e) != key);
+        self.env.len() != len
+    }
+}
+
+fn env_key(entry: &str) -> &str {
+    entry.split('=').next().unwrap_or(entry)
 }
 
 /// LinuxCapabilities specifies the whitelist of capabilities that are kept for a process.
@@ -98,22 +574,74 @@ pub struct Process {
 #[builder(default, setter(into))]
 pub struct LinuxCapabilities {
     /// Bounding is the set of capabilities checked by the kernel.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     bounding: Vec<String>,
     /// Effective is the set of capabilities checked by the kernel.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     effective: Vec<String>,
     /// Inheritable is the capabilities preserved across execve.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     inheritable: Vec<String>,
     /// Permitted is the limiting superset for effective capabilities.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     permitted: Vec<String>,
     /// Ambient is the ambient set of capabilities that are kept.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     ambient: Vec<String>,
 }
 
+impl LinuxCapabilities {
+    /// Bounding is the set of capabilities checked by the kernel.
+    pub fn bounding(&self) -> &[String] {
+        &self.bounding
+    }
+
+    /// Effective is the set of capabilities checked by the kernel.
+    pub fn effective(&self) -> &[String] {
+        &self.effective
+    }
+
+    /// Inheritable is the capabilities preserved across execve.
+    pub fn inheritable(&self) -> &[String] {
+        &self.inheritable
+    }
+
+    /// Permitted is the limiting superset for effective capabilities.
+    pub fn permitted(&self) -> &[String] {
+        &self.permitted
+    }
+
+    /// Ambient is the ambient set of capabilities that are kept.
+    pub fn ambient(&self) -> &[String] {
+        &self.ambient
+    }
+
+    /// Mutable access to [`bounding`](Self::bounding).
+    pub fn bounding_mut(&mut self) -> &mut Vec<String> {
+        &mut self.bounding
+    }
+
+    /// Mutable access to [`effective`](Self::effective).
+    pub fn effective_mut(&mut self) -> &mut Vec<String> {
+        &mut self.effective
+    }
+
+    /// Mutable access to [`inheritable`](Self::inheritable).
+    pub fn inheritable_mut(&mut self) -> &mut Vec<String> {
+        &mut self.inheritable
+    }
+
+    /// Mutable access to [`permitted`](Self::permitted).
+    pub fn permitted_mut(&mut self) -> &mut Vec<String> {
+        &mut self.permitted
+    }
+
+    /// Mutable access to [`ambient`](Self::ambient).
+    pub fn ambient_mut(&mut self) -> &mut Vec<String> {
+        &mut self.ambient
+    }
+}
+
 /// ConsoleSizeBox specifies dimensions of a rectangle. Used for specifying the size of a console.
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -124,6 +652,28 @@ pub struct ConsoleSizeBox {
     width: u32,
 }
 
+impl ConsoleSizeBox {
+    /// Height is the vertical dimension of a box.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Width is the horizontal dimension of a box.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Mutable access to [`height`](Self::height).
+    pub fn height_mut(&mut self) -> &mut u32 {
+        &mut self.height
+    }
+
+    /// Mutable access to [`width`](Self::width).
+    pub fn width_mut(&mut self) -> &mut u32 {
+        &mut self.width
+    }
+}
+
 /// User specifies specific user (and group) information for the container process.
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -138,6 +688,61 @@ pub struct User {
     /// AdditionalGids are additional group ids set for the container's process.
     #[serde(skip_serializing_if = "Vec::is_empty", rename = "additionalGids", default)]
     additional_gids: Vec<u32>,
+    /// Username is the user name (Windows only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+}
+
+impl User {
+    /// UID is the user id.
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+
+    /// GID is the group id.
+    pub fn gid(&self) -> u32 {
+        self.gid
+    }
+
+    /// Umask is the umask for the init process.
+    pub fn umask(&self) -> Option<u32> {
+        self.umask
+    }
+
+    /// AdditionalGids are additional group ids set for the container's process.
+    pub fn additional_gids(&self) -> &[u32] {
+        &self.additional_gids
+    }
+
+    /// Username is the user name (Windows only).
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// Mutable access to [`uid`](Self::uid).
+    pub fn uid_mut(&mut self) -> &mut u32 {
+        &mut self.uid
+    }
+
+    /// Mutable access to [`gid`](Self::gid).
+    pub fn gid_mut(&mut self) -> &mut u32 {
+        &mut self.gid
+    }
+
+    /// Mutable access to [`umask`](Self::umask).
+    pub fn umask_mut(&mut self) -> &mut Option<u32> {
+        &mut self.umask
+    }
+
+    /// Mutable access to [`additional_gids`](Self::additional_gids).
+    pub fn additional_gids_mut(&mut self) -> &mut Vec<u32> {
+        &mut self.additional_gids
+    }
+
+    /// Mutable access to [`username`](Self::username).
+    pub fn username_mut(&mut self) -> &mut Option<String> {
+        &mut self.username
+    }
 }
 
 /// Root contains information about the container's root filesystem on the host.
@@ -151,6 +756,28 @@ pub struct Root {
     readonly: Option<bool>,
 }
 
+impl Root {
+    /// Path is the absolute path to the container's root filesystem.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Readonly makes the root filesystem for the container readonly before the process is executed.
+    pub fn readonly(&self) -> Option<bool> {
+        self.readonly
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`readonly`](Self::readonly).
+    pub fn readonly_mut(&mut self) -> &mut Option<bool> {
+        &mut self.readonly
+    }
+}
+
 /// Mount specifies a mount for a container.
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -166,21 +793,139 @@ pub struct Mount {
     /// Options are fstab style mount options.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     options: Vec<String>,
+    /// UIDMappings specifies the user mappings for an ID-mapped mount.
+    /// Requires the `idmap` or `ridmap` option and a user namespace.
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "uidMappings", default)]
+    uid_mappings: Vec<LinuxIDMapping>,
+    /// GIDMappings specifies the group mappings for an ID-mapped mount.
+    /// Requires the `idmap` or `ridmap` option and a user namespace.
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "gidMappings", default)]
+    gid_mappings: Vec<LinuxIDMapping>,
+}
+
+impl Mount {
+    /// Destination is the absolute path where the mount will be placed in the container.
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    /// Type specifies the mount kind.
+    pub fn mount_type(&self) -> Option<&str> {
+        self.mount_type.as_deref()
+    }
+
+    /// Source specifies the source path of the mount.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// Options are fstab style mount options.
+    pub fn options(&self) -> &[String] {
+        &self.options
+    }
+
+    /// UIDMappings specifies the user mappings for an ID-mapped mount.
+    /// Requires the `idmap` or `ridmap` option and a user namespace.
+    pub fn uid_mappings(&self) -> &[LinuxIDMapping] {
+        &self.uid_mappings
+    }
+
+    /// GIDMappings specifies the group mappings for an ID-mapped mount.
+    /// Requires the `idmap` or `ridmap` option and a user namespace.
+    pub fn gid_mappings(&self) -> &[LinuxIDMapping] {
+        &self.gid_mappings
+    }
+
+    /// Mutable access to [`destination`](Self::destination).
+    pub fn destination_mut(&mut self) -> &mut String {
+        &mut self.destination
+    }
+
+    /// Mutable access to [`mount_type`](Self::mount_type).
+    pub fn mount_type_mut(&mut self) -> &mut Option<String> {
+        &mut self.mount_type
+    }
+
+    /// Mutable access to [`source`](Self::source).
+    pub fn source_mut(&mut self) -> &mut Option<String> {
+        &mut self.source
+    }
+
+    /// Mutable access to [`options`](Self::options).
+    pub fn options_mut(&mut self) -> &mut Vec<String> {
+        &mut self.options
+    }
+
+    /// Mutable access to [`uid_mappings`](Self::uid_mappings).
+    pub fn uid_mappings_mut(&mut self) -> &mut Vec<LinuxIDMapping> {
+        &mut self.uid_mappings
+    }
+
+    /// Mutable access to [`gid_mappings`](Self::gid_mappings).
+    pub fn gid_mappings_mut(&mut self) -> &mut Vec<LinuxIDMapping> {
+        &mut self.gid_mappings
+    }
 }
 
 /// Hook specifies a command that is run at a particular event in the lifecycle of a container
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
 pub struct Hook {
+    /// Path is the absolute path to the hook executable.
     path: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// Args are the arguments passed to the hook, including argv[0].
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     args: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// Env is the environment of the hook.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     env: Vec<String>,
+    /// Timeout is the number of seconds before aborting the hook.
     #[serde(skip_serializing_if = "Option::is_none")]
     timeout: Option<i32>,
 }
 
+impl Hook {
+    /// Path is the absolute path to the hook executable.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Args are the arguments passed to the hook, including argv[0].
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// Env is the environment of the hook.
+    pub fn env(&self) -> &[String] {
+        &self.env
+    }
+
+    /// Timeout is the number of seconds before aborting the hook.
+    pub fn timeout(&self) -> Option<i32> {
+        self.timeout
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`args`](Self::args).
+    pub fn args_mut(&mut self) -> &mut Vec<String> {
+        &mut self.args
+    }
+
+    /// Mutable access to [`env`](Self::env).
+    pub fn env_mut(&mut self) -> &mut Vec<String> {
+        &mut self.env
+    }
+
+    /// Mutable access to [`timeout`](Self::timeout).
+    pub fn timeout_mut(&mut self) -> &mut Option<i32> {
+        &mut self.timeout
+    }
+}
+
 /// Hooks specifies a command that is run in the container at a particular event in the lifecycle of a container
 /// Hooks for container setup and teardown
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
@@ -204,14 +949,112 @@ pub struct Hooks {
     start_container: Vec<Hook>,
     /// Poststart is a list of hooks to be run after the container process is started.
     /// It is called in the Runtime Namespace
-    #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    poststart: Vec<String>,
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_legacy_hooks",
+        default
+    )]
+    poststart: Vec<Hook>,
     /// Poststop is a list of hooks to be run after the container process exits.
     /// It is called in the Runtime Namespace
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     poststop: Vec<Hook>,
 }
 
+impl Hooks {
+    /// Prestart is Deprecated. Prestart is a list of hooks to be run before the container process is executed.
+    /// It is called in the Runtime Namespace
+    pub fn prestart(&self) -> &[Hook] {
+        &self.prestart
+    }
+
+    /// CreateRuntime is a list of hooks to be run after the container has been created but before pivot_root or any equivalent operation has been called
+    /// It is called in the Runtime Namespace
+    pub fn create_runtime(&self) -> &[Hook] {
+        &self.create_runtime
+    }
+
+    /// CreateContainer is a list of hooks to be run after the container has been created but before pivot_root or any equivalent operation has been called
+    /// It is called in the Container Namespace
+    pub fn create_container(&self) -> &[Hook] {
+        &self.create_container
+    }
+
+    /// StartContainer is a list of hooks to be run after the start operation is called but before the container process is started
+    /// It is called in the Container Namespace
+    pub fn start_container(&self) -> &[Hook] {
+        &self.start_container
+    }
+
+    /// Poststart is a list of hooks to be run after the container process is started.
+    /// It is called in the Runtime Namespace
+    pub fn poststart(&self) -> &[Hook] {
+        &self.poststart
+    }
+
+    /// Poststop is a list of hooks to be run after the container process exits.
+    /// It is called in the Runtime Namespace
+    pub fn poststop(&self) -> &[Hook] {
+        &self.poststop
+    }
+
+    /// Mutable access to [`prestart`](Self::prestart).
+    pub fn prestart_mut(&mut self) -> &mut Vec<Hook> {
+        &mut self.prestart
+    }
+
+    /// Mutable access to [`create_runtime`](Self::create_runtime).
+    pub fn create_runtime_mut(&mut self) -> &mut Vec<Hook> {
+        &mut self.create_runtime
+    }
+
+    /// Mutable access to [`create_container`](Self::create_container).
+    pub fn create_container_mut(&mut self) -> &mut Vec<Hook> {
+        &mut self.create_container
+    }
+
+    /// Mutable access to [`start_container`](Self::start_container).
+    pub fn start_container_mut(&mut self) -> &mut Vec<Hook> {
+        &mut self.start_container
+    }
+
+    /// Mutable access to [`poststart`](Self::poststart).
+    pub fn poststart_mut(&mut self) -> &mut Vec<Hook> {
+        &mut self.poststart
+    }
+
+    /// Mutable access to [`poststop`](Self::poststop).
+    pub fn poststop_mut(&mut self) -> &mut Vec<Hook> {
+        &mut self.poststop
+    }
+}
+
+/// Configs written by earlier versions of this crate stored poststart hooks as bare path
+/// strings; those are still accepted alongside full hook objects.
+fn deserialize_legacy_hooks<'de, D>(deserializer: D) -> Result<Vec<Hook>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum LegacyHook {
+        Hook(Hook),
+        Path(String),
+    }
+
+    let hooks = Vec::<LegacyHook>::deserialize(deserializer)?;
+    Ok(hooks
+        .into_iter()
+        .map(|hook| match hook {
+            LegacyHook::Hook(hook) => hook,
+            LegacyHook::Path(path) => Hook {
+                path,
+                ..Hook::default()
+            },
+        })
+        .collect())
+}
+
 /// Linux contains platform-specific configuration for Linux based containers.
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -235,7 +1078,7 @@ pub struct Linux {
     #[serde(skip_serializing_if = "Option::is_none", rename = "cgroupsPath")]
     cgroups_path: Option<String>,
     /// Namespaces contains the namespaces that are created and/or joined by the container
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     namespaces: Vec<LinuxNamespace>,
     /// Devices are a list of device nodes that are created for the container
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
@@ -247,10 +1090,10 @@ pub struct Linux {
     #[serde(skip_serializing_if = "Option::is_none", rename = "rootfsPropagation")]
     rootfs_propagation: Option<String>,
     /// MaskedPaths masks over the provided paths inside the container.
-    #[serde(skip_serializing_if = "Vec::is_empty", rename = "maskedPaths")]
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "maskedPaths", default)]
     masked_paths: Vec<String>,
     /// ReadonlyPaths sets the provided paths as RO inside the container.
-    #[serde(skip_serializing_if = "Vec::is_empty", rename = "readonlyPaths")]
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "readonlyPaths", default)]
     readonly_paths: Vec<String>,
     /// MountLabel specifies the selinux context for the mounts in the container.
     #[serde(skip_serializing_if = "Option::is_none", rename = "mountLabel")]
@@ -262,34 +1105,316 @@ pub struct Linux {
     /// Personality contains configuration for the Linux personality syscall
     #[serde(skip_serializing_if = "Option::is_none")]
     personality: Option<LinuxPersonality>,
+    /// TimeOffsets specifies the offset for supporting time namespaces, keyed by
+    /// clock name (`monotonic` or `boottime`).
+    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "timeOffsets", default)]
+    time_offsets: HashMap<String, LinuxTimeOffset>,
+    /// NetDevices are key-value pairs, keyed by network device name on the host, moved to the container's network namespace.
+    #[serde(skip_serializing_if = "HashMap::is_empty", rename = "netDevices", default)]
+    net_devices: HashMap<String, LinuxNetDevice>,
 }
 
-/// LinuxNamespace is the configuration for a Linux namespace
-#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
-#[builder(default, setter(into))]
-pub struct LinuxNamespace {
-    /// Type is the type of namespace
-    #[serde(rename = "type")]
-    namespace_type: String,
-    /// Path is a path to an existing namespace persisted on disk that can be joined
-    /// and is of the same type
-    #[serde(skip_serializing_if = "Option::is_none")]
-    path: Option<String>,
-}
+impl Linux {
+    /// UIDMapping specifies user mappings for supporting user namespaces.
+    pub fn uid_mappings(&self) -> &[LinuxIDMapping] {
+        &self.uid_mappings
+    }
 
-/// LinuxIDMapping specifies UID/GID mappings
-#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
-#[builder(default, setter(into))]
-pub struct LinuxIDMapping {
-    /// ContainerID is the starting UID/GID in the container
-    #[serde(rename = "containerID")]
-    container_id: u32,
-    /// HostID is the starting UID/GID on the host to be mapped to 'ContainerID'
-    #[serde(rename = "hostID")]
-    host_id: u32,
-    /// Size is the number of IDs to be mapped
-    size: u32,
-}
+    /// GIDMapping specifies group mappings for supporting user namespaces.
+    pub fn gid_mappings(&self) -> &[LinuxIDMapping] {
+        &self.gid_mappings
+    }
+
+    /// Sysctl are a set of key value pairs that are set for the container on start
+    pub fn sysctl(&self) -> &HashMap<String, String> {
+        &self.sysctl
+    }
+
+    /// Resources contain cgroup information for handling resource constraints
+    /// for the container
+    pub fn resources(&self) -> Option<&LinuxResources> {
+        self.resources.as_ref()
+    }
+
+    /// CgroupsPath specifies the path to cgroups that are created and/or joined by the container.
+    /// The path is expected to be relative to the cgroups mountpoint.
+    /// If resources are specified, the cgroups at CgroupsPath will be updated based on resources.
+    pub fn cgroups_path(&self) -> Option<&str> {
+        self.cgroups_path.as_deref()
+    }
+
+    /// Namespaces contains the namespaces that are created and/or joined by the container
+    pub fn namespaces(&self) -> &[LinuxNamespace] {
+        &self.namespaces
+    }
+
+    /// Devices are a list of device nodes that are created for the container
+    pub fn devices(&self) -> &[LinuxDevice] {
+        &self.devices
+    }
+
+    /// Seccomp specifies the seccomp security settings for the container.
+    pub fn seccomp(&self) -> Option<&LinuxSeccomp> {
+        self.seccomp.as_ref()
+    }
+
+    /// RootfsPropagation is the rootfs mount propagation mode for the container.
+    pub fn rootfs_propagation(&self) -> Option<&str> {
+        self.rootfs_propagation.as_deref()
+    }
+
+    /// MaskedPaths masks over the provided paths inside the container.
+    pub fn masked_paths(&self) -> &[String] {
+        &self.masked_paths
+    }
+
+    /// ReadonlyPaths sets the provided paths as RO inside the container.
+    pub fn readonly_paths(&self) -> &[String] {
+        &self.readonly_paths
+    }
+
+    /// MountLabel specifies the selinux context for the mounts in the container.
+    pub fn mount_label(&self) -> Option<&str> {
+        self.mount_label.as_deref()
+    }
+
+    /// IntelRdt contains Intel Resource Director Technology (RDT) information for
+    /// handling resource constraints (e.g., L3 cache, memory bandwidth) for the container
+    pub fn intel_rdt(&self) -> Option<&LinuxIntelRdt> {
+        self.intel_rdt.as_ref()
+    }
+
+    /// Personality contains configuration for the Linux personality syscall
+    pub fn personality(&self) -> Option<&LinuxPersonality> {
+        self.personality.as_ref()
+    }
+
+    /// TimeOffsets specifies the offset for supporting time namespaces, keyed by
+    /// clock name (`monotonic` or `boottime`).
+    pub fn time_offsets(&self) -> &HashMap<String, LinuxTimeOffset> {
+        &self.time_offsets
+    }
+
+    /// NetDevices are key-value pairs, keyed by network device name on the host, moved to the container's network namespace.
+    pub fn net_devices(&self) -> &HashMap<String, LinuxNetDevice> {
+        &self.net_devices
+    }
+
+    /// Mutable access to [`uid_mappings`](Self::uid_mappings).
+    pub fn uid_mappings_mut(&mut self) -> &mut Vec<LinuxIDMapping> {
+        &mut self.uid_mappings
+    }
+
+    /// Mutable access to [`gid_mappings`](Self::gid_mappings).
+    pub fn gid_mappings_mut(&mut self) -> &mut Vec<LinuxIDMapping> {
+        &mut self.gid_mappings
+    }
+
+    /// Mutable access to [`sysctl`](Self::sysctl).
+    pub fn sysctl_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.sysctl
+    }
+
+    /// Mutable access to [`resources`](Self::resources).
+    pub fn resources_mut(&mut self) -> &mut Option<LinuxResources> {
+        &mut self.resources
+    }
+
+    /// Mutable access to [`cgroups_path`](Self::cgroups_path).
+    pub fn cgroups_path_mut(&mut self) -> &mut Option<String> {
+        &mut self.cgroups_path
+    }
+
+    /// Mutable access to [`namespaces`](Self::namespaces).
+    pub fn namespaces_mut(&mut self) -> &mut Vec<LinuxNamespace> {
+        &mut self.namespaces
+    }
+
+    /// Mutable access to [`devices`](Self::devices).
+    pub fn devices_mut(&mut self) -> &mut Vec<LinuxDevice> {
+        &mut self.devices
+    }
+
+    /// Mutable access to [`seccomp`](Self::seccomp).
+    pub fn seccomp_mut(&mut self) -> &mut Option<LinuxSeccomp> {
+        &mut self.seccomp
+    }
+
+    /// Mutable access to [`rootfs_propagation`](Self::rootfs_propagation).
+    pub fn rootfs_propagation_mut(&mut self) -> &mut Option<String> {
+        &mut self.rootfs_propagation
+    }
+
+    /// Mutable access to [`masked_paths`](Self::masked_paths).
+    pub fn masked_paths_mut(&mut self) -> &mut Vec<String> {
+        &mut self.masked_paths
+    }
+
+    /// Mutable access to [`readonly_paths`](Self::readonly_paths).
+    pub fn readonly_paths_mut(&mut self) -> &mut Vec<String> {
+        &mut self.readonly_paths
+    }
+
+    /// Mutable access to [`mount_label`](Self::mount_label).
+    pub fn mount_label_mut(&mut self) -> &mut Option<String> {
+        &mut self.mount_label
+    }
+
+    /// Mutable access to [`intel_rdt`](Self::intel_rdt).
+    pub fn intel_rdt_mut(&mut self) -> &mut Option<LinuxIntelRdt> {
+        &mut self.intel_rdt
+    }
+
+    /// Mutable access to [`personality`](Self::personality).
+    pub fn personality_mut(&mut self) -> &mut Option<LinuxPersonality> {
+        &mut self.personality
+    }
+
+    /// Mutable access to [`time_offsets`](Self::time_offsets).
+    pub fn time_offsets_mut(&mut self) -> &mut HashMap<String, LinuxTimeOffset> {
+        &mut self.time_offsets
+    }
+
+    /// Mutable access to [`net_devices`](Self::net_devices).
+    pub fn net_devices_mut(&mut self) -> &mut HashMap<String, LinuxNetDevice> {
+        &mut self.net_devices
+    }
+
+    /// Reports whether a namespace of the given kind is configured.
+    pub fn has_namespace(&self, kind: LinuxNamespaceType) -> bool {
+        self.namespace(kind).is_some()
+    }
+
+    /// Returns the namespace of the given kind, if configured.
+    pub fn namespace(&self, kind: LinuxNamespaceType) -> Option<&LinuxNamespace> {
+        self.namespaces.iter().find(|ns| ns.namespace_type == kind)
+    }
+
+    /// Adds a namespace of the given kind, replacing an existing one of the same kind.
+    /// A `path` joins an existing namespace instead of creating a new one.
+    pub fn add_namespace(&mut self, kind: LinuxNamespaceType, path: Option<String>) {
+        let namespace = LinuxNamespace {
+            namespace_type: kind,
+            path,
+        };
+        match self.namespaces.iter_mut().find(|ns| ns.namespace_type == kind) {
+            Some(existing) => *existing = namespace,
+            None => self.namespaces.push(namespace),
+        }
+    }
+
+    /// Removes the namespace of the given kind, returning it if it was configured.
+    pub fn remove_namespace(&mut self, kind: LinuxNamespaceType) -> Option<LinuxNamespace> {
+        let index = self.namespaces.iter().position(|ns| ns.namespace_type == kind)?;
+        Some(self.namespaces.remove(index))
+    }
+}
+
+/// LinuxNamespace is the configuration for a Linux namespace
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct LinuxNamespace {
+    /// Type is the type of namespace
+    #[serde(rename = "type")]
+    namespace_type: LinuxNamespaceType,
+    /// Path is a path to an existing namespace persisted on disk that can be joined
+    /// and is of the same type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+impl LinuxNamespace {
+    /// Type is the type of namespace
+    pub fn namespace_type(&self) -> LinuxNamespaceType {
+        self.namespace_type
+    }
+
+    /// Path is a path to an existing namespace persisted on disk that can be joined
+    /// and is of the same type
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Mutable access to [`namespace_type`](Self::namespace_type).
+    pub fn namespace_type_mut(&mut self) -> &mut LinuxNamespaceType {
+        &mut self.namespace_type
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut Option<String> {
+        &mut self.path
+    }
+}
+
+string_enum! {
+    /// LinuxNamespaceType is one of the Linux namespaces
+    #[derive(Default)]
+    pub enum LinuxNamespaceType {
+        /// PID namespace for isolation of process PIDs
+        #[default]
+        Pid = "pid",
+        /// Network namespace for isolation of network devices, stacks, ports, etc
+        Network = "network",
+        /// Mount namespace for isolation of mount points
+        Mount = "mount",
+        /// IPC namespace for isolation of System V IPC, POSIX message queues
+        Ipc = "ipc",
+        /// UTS namespace for isolation of hostname and NIS domain name
+        Uts = "uts",
+        /// User namespace for isolation of user and group IDs
+        User = "user",
+        /// Cgroup namespace for isolation of cgroup hierarchies
+        Cgroup = "cgroup",
+        /// Time namespace for isolation of the clocks
+        Time = "time",
+    }
+}
+
+/// LinuxIDMapping specifies UID/GID mappings
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct LinuxIDMapping {
+    /// ContainerID is the starting UID/GID in the container
+    #[serde(rename = "containerID")]
+    container_id: u32,
+    /// HostID is the starting UID/GID on the host to be mapped to 'ContainerID'
+    #[serde(rename = "hostID")]
+    host_id: u32,
+    /// Size is the number of IDs to be mapped
+    size: u32,
+}
+
+impl LinuxIDMapping {
+    /// ContainerID is the starting UID/GID in the container
+    pub fn container_id(&self) -> u32 {
+        self.container_id
+    }
+
+    /// HostID is the starting UID/GID on the host to be mapped to 'ContainerID'
+    pub fn host_id(&self) -> u32 {
+        self.host_id
+    }
+
+    /// Size is the number of IDs to be mapped
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Mutable access to [`container_id`](Self::container_id).
+    pub fn container_id_mut(&mut self) -> &mut u32 {
+        &mut self.container_id
+    }
+
+    /// Mutable access to [`host_id`](Self::host_id).
+    pub fn host_id_mut(&mut self) -> &mut u32 {
+        &mut self.host_id
+    }
+
+    /// Mutable access to [`size`](Self::size).
+    pub fn size_mut(&mut self) -> &mut u32 {
+        &mut self.size
+    }
+}
 
 /// POSIXRlimit type and restrictions
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
@@ -304,6 +1429,266 @@ pub struct POSIXRlimit {
     soft: u64,
 }
 
+impl POSIXRlimit {
+    /// Type of the rlimit to set
+    pub fn rlimit_type(&self) -> &str {
+        &self.rlimit_type
+    }
+
+    /// Hard is the hard limit for the specified type
+    pub fn hard(&self) -> u64 {
+        self.hard
+    }
+
+    /// Soft is the soft limit for the specified type
+    pub fn soft(&self) -> u64 {
+        self.soft
+    }
+
+    /// Mutable access to [`rlimit_type`](Self::rlimit_type).
+    pub fn rlimit_type_mut(&mut self) -> &mut String {
+        &mut self.rlimit_type
+    }
+
+    /// Mutable access to [`hard`](Self::hard).
+    pub fn hard_mut(&mut self) -> &mut u64 {
+        &mut self.hard
+    }
+
+    /// Mutable access to [`soft`](Self::soft).
+    pub fn soft_mut(&mut self) -> &mut u64 {
+        &mut self.soft
+    }
+}
+
+string_enum! {
+    /// LinuxSchedulerPolicy is the scheduling policy passed to sched_setattr(2).
+    #[derive(Default)]
+    pub enum LinuxSchedulerPolicy {
+        /// The default round-robin time-sharing policy.
+        #[default]
+        Other = "SCHED_OTHER",
+        /// First-in, first-out real-time policy.
+        Fifo = "SCHED_FIFO",
+        /// Round-robin real-time policy.
+        Rr = "SCHED_RR",
+        /// Batch-style execution of processes.
+        Batch = "SCHED_BATCH",
+        /// Isochronous policy, reserved but not implemented by the kernel.
+        Iso = "SCHED_ISO",
+        /// Running very low priority background jobs.
+        Idle = "SCHED_IDLE",
+        /// Earliest-deadline-first real-time policy.
+        Deadline = "SCHED_DEADLINE",
+    }
+}
+
+string_enum! {
+    /// LinuxSchedulerFlag is a flag passed to sched_setattr(2).
+    pub enum LinuxSchedulerFlag {
+        /// Children created by fork(2) do not inherit privileged policies.
+        ResetOnFork = "SCHED_FLAG_RESET_ON_FORK",
+        /// Allow a SCHED_DEADLINE thread to reclaim unused bandwidth.
+        Reclaim = "SCHED_FLAG_RECLAIM",
+        /// Send SIGXCPU when a SCHED_DEADLINE thread overruns its runtime.
+        DlOverrun = "SCHED_FLAG_DL_OVERRUN",
+        /// Keep the current scheduling policy.
+        KeepPolicy = "SCHED_FLAG_KEEP_POLICY",
+        /// Keep the current scheduling parameters.
+        KeepParams = "SCHED_FLAG_KEEP_PARAMS",
+        /// Apply the minimum utilization clamp.
+        UtilClampMin = "SCHED_FLAG_UTIL_CLAMP_MIN",
+        /// Apply the maximum utilization clamp.
+        UtilClampMax = "SCHED_FLAG_UTIL_CLAMP_MAX",
+    }
+}
+
+/// Scheduler represents the scheduling attributes for a process. It is based on
+/// the Linux sched_setattr(2) syscall.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Scheduler {
+    /// Policy represents the scheduling policy (e.g., SCHED_FIFO, SCHED_RR, SCHED_OTHER).
+    policy: LinuxSchedulerPolicy,
+    /// Nice is the nice value for the process, which affects its priority.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nice: Option<i32>,
+    /// Priority represents the static priority of the process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<i32>,
+    /// Flags is an array of scheduling flags.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    flags: Vec<LinuxSchedulerFlag>,
+    /// Runtime is the amount of time in nanoseconds during which the process
+    /// is allowed to run in a given period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    runtime: Option<u64>,
+    /// Deadline is the absolute deadline for the process to complete its execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deadline: Option<u64>,
+    /// Period is the length of the period in nanoseconds used for determining the process runtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period: Option<u64>,
+}
+
+impl Scheduler {
+    /// Policy represents the scheduling policy (e.g., SCHED_FIFO, SCHED_RR, SCHED_OTHER).
+    pub fn policy(&self) -> LinuxSchedulerPolicy {
+        self.policy
+    }
+
+    /// Nice is the nice value for the process, which affects its priority.
+    pub fn nice(&self) -> Option<i32> {
+        self.nice
+    }
+
+    /// Priority represents the static priority of the process.
+    pub fn priority(&self) -> Option<i32> {
+        self.priority
+    }
+
+    /// Flags is an array of scheduling flags.
+    pub fn flags(&self) -> &[LinuxSchedulerFlag] {
+        &self.flags
+    }
+
+    /// Runtime is the amount of time in nanoseconds during which the process
+    /// is allowed to run in a given period.
+    pub fn runtime(&self) -> Option<u64> {
+        self.runtime
+    }
+
+    /// Deadline is the absolute deadline for the process to complete its execution.
+    pub fn deadline(&self) -> Option<u64> {
+        self.deadline
+    }
+
+    /// Period is the length of the period in nanoseconds used for determining the process runtime.
+    pub fn period(&self) -> Option<u64> {
+        self.period
+    }
+
+    /// Mutable access to [`policy`](Self::policy).
+    pub fn policy_mut(&mut self) -> &mut LinuxSchedulerPolicy {
+        &mut self.policy
+    }
+
+    /// Mutable access to [`nice`](Self::nice).
+    pub fn nice_mut(&mut self) -> &mut Option<i32> {
+        &mut self.nice
+    }
+
+    /// Mutable access to [`priority`](Self::priority).
+    pub fn priority_mut(&mut self) -> &mut Option<i32> {
+        &mut self.priority
+    }
+
+    /// Mutable access to [`flags`](Self::flags).
+    pub fn flags_mut(&mut self) -> &mut Vec<LinuxSchedulerFlag> {
+        &mut self.flags
+    }
+
+    /// Mutable access to [`runtime`](Self::runtime).
+    pub fn runtime_mut(&mut self) -> &mut Option<u64> {
+        &mut self.runtime
+    }
+
+    /// Mutable access to [`deadline`](Self::deadline).
+    pub fn deadline_mut(&mut self) -> &mut Option<u64> {
+        &mut self.deadline
+    }
+
+    /// Mutable access to [`period`](Self::period).
+    pub fn period_mut(&mut self) -> &mut Option<u64> {
+        &mut self.period
+    }
+}
+
+string_enum! {
+    /// IOPriorityClass represents an I/O scheduling class.
+    #[derive(Default)]
+    pub enum IOPriorityClass {
+        /// Real-time class, given first access to the disk.
+        Rt = "IOPRIO_CLASS_RT",
+        /// Best-effort class, the default for most processes.
+        #[default]
+        Be = "IOPRIO_CLASS_BE",
+        /// Idle class, only served when no other process needs the disk.
+        Idle = "IOPRIO_CLASS_IDLE",
+    }
+}
+
+/// LinuxIOPriority represents I/O priority settings for the container's processes within the process group.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct LinuxIOPriority {
+    /// Class is the I/O scheduling class.
+    class: IOPriorityClass,
+    /// Priority is the priority level within the class, from 0 (highest) to 7 (lowest).
+    #[serde(default)]
+    priority: i32,
+}
+
+impl LinuxIOPriority {
+    /// Class is the I/O scheduling class.
+    pub fn class(&self) -> IOPriorityClass {
+        self.class
+    }
+
+    /// Priority is the priority level within the class, from 0 (highest) to 7 (lowest).
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Mutable access to [`class`](Self::class).
+    pub fn class_mut(&mut self) -> &mut IOPriorityClass {
+        &mut self.class
+    }
+
+    /// Mutable access to [`priority`](Self::priority).
+    pub fn priority_mut(&mut self) -> &mut i32 {
+        &mut self.priority
+    }
+}
+
+/// CPUAffinity specifies process' CPU affinity.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct CPUAffinity {
+    /// Initial is the CPU list (e.g. "0-3,7") the runtime process is pinned
+    /// to before it joins the container's cgroup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    initial: Option<String>,
+    /// Final is the CPU list the process is pinned to after it joins the
+    /// container's cgroup, just before exec.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "final")]
+    final_cpus: Option<String>,
+}
+
+impl CPUAffinity {
+    /// Initial is the CPU list (e.g. "0-3,7") the runtime process is pinned
+    /// to before it joins the container's cgroup.
+    pub fn initial(&self) -> Option<&str> {
+        self.initial.as_deref()
+    }
+
+    /// Final is the CPU list the process is pinned to after it joins the
+    /// container's cgroup, just before exec.
+    pub fn final_cpus(&self) -> Option<&str> {
+        self.final_cpus.as_deref()
+    }
+
+    /// Mutable access to [`initial`](Self::initial).
+    pub fn initial_mut(&mut self) -> &mut Option<String> {
+        &mut self.initial
+    }
+
+    /// Mutable access to [`final_cpus`](Self::final_cpus).
+    pub fn final_cpus_mut(&mut self) -> &mut Option<String> {
+        &mut self.final_cpus
+    }
+}
+
 // LinuxHugepageLimit structure corresponds to limiting kernel hugepages
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -316,6 +1701,29 @@ pub struct LinuxHugepageLimit {
     limit: u64,
 }
 
+impl LinuxHugepageLimit {
+    /// Pagesize is the hugepage size
+    /// Format: "<size><unit-prefix>B' (e.g. 64KB, 2MB, 1GB, etc.)
+    pub fn page_size(&self) -> &str {
+        &self.page_size
+    }
+
+    /// Limit is the limit of "hugepagesize" hugetlb usage
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Mutable access to [`page_size`](Self::page_size).
+    pub fn page_size_mut(&mut self) -> &mut String {
+        &mut self.page_size
+    }
+
+    /// Mutable access to [`limit`](Self::limit).
+    pub fn limit_mut(&mut self) -> &mut u64 {
+        &mut self.limit
+    }
+}
+
 /// LinuxInterfacePriority for network interfaces
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -326,6 +1734,28 @@ pub struct LinuxInterfacePriority {
     priority: u32,
 }
 
+impl LinuxInterfacePriority {
+    /// Name is the name of the network interface
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Priority for the interface
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    /// Mutable access to [`name`](Self::name).
+    pub fn name_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+
+    /// Mutable access to [`priority`](Self::priority).
+    pub fn priority_mut(&mut self) -> &mut u32 {
+        &mut self.priority
+    }
+}
+
 /// LinuxWeightDevice struct holds a `major:minor weight` pair for weightDevice
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -342,6 +1772,48 @@ pub struct LinuxWeightDevice {
     leaf_weight: Option<u16>,
 }
 
+impl LinuxWeightDevice {
+    /// Major is the device's major number.
+    pub fn major(&self) -> i64 {
+        self.major
+    }
+
+    /// Minor is the device's minor number.
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    /// Weight is the bandwidth rate for the device.
+    pub fn weight(&self) -> Option<u16> {
+        self.weight
+    }
+
+    /// LeafWeight is the bandwidth rate for the device while competing with the cgroup's child cgroups, CFQ scheduler only
+    pub fn leaf_weight(&self) -> Option<u16> {
+        self.leaf_weight
+    }
+
+    /// Mutable access to [`major`](Self::major).
+    pub fn major_mut(&mut self) -> &mut i64 {
+        &mut self.major
+    }
+
+    /// Mutable access to [`minor`](Self::minor).
+    pub fn minor_mut(&mut self) -> &mut i64 {
+        &mut self.minor
+    }
+
+    /// Mutable access to [`weight`](Self::weight).
+    pub fn weight_mut(&mut self) -> &mut Option<u16> {
+        &mut self.weight
+    }
+
+    /// Mutable access to [`leaf_weight`](Self::leaf_weight).
+    pub fn leaf_weight_mut(&mut self) -> &mut Option<u16> {
+        &mut self.leaf_weight
+    }
+}
+
 /// LinuxThrottleDevice struct holds a `major:minor rate_per_second` pai
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -354,6 +1826,38 @@ pub struct LinuxThrottleDevice {
     rate: u64,
 }
 
+impl LinuxThrottleDevice {
+    /// Major is the device's major number.
+    pub fn major(&self) -> i64 {
+        self.major
+    }
+
+    /// Minor is the device's minor number.
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    /// Rate is the IO rate limit per cgroup per device
+    pub fn rate(&self) -> u64 {
+        self.rate
+    }
+
+    /// Mutable access to [`major`](Self::major).
+    pub fn major_mut(&mut self) -> &mut i64 {
+        &mut self.major
+    }
+
+    /// Mutable access to [`minor`](Self::minor).
+    pub fn minor_mut(&mut self) -> &mut i64 {
+        &mut self.minor
+    }
+
+    /// Mutable access to [`rate`](Self::rate).
+    pub fn rate_mut(&mut self) -> &mut u64 {
+        &mut self.rate
+    }
+}
+
 /// LinuxBlockIO for Linux cgroup 'blkio' resource management
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -365,34 +1869,110 @@ pub struct LinuxBlockIO {
     #[serde(skip_serializing_if = "Option::is_none", rename = "leafWeight")]
     leaf_weight: Option<u16>,
     /// Weight per cgroup per device, can override BlkioWeight
-    #[serde(skip_serializing_if = "Vec::is_empty", rename = "weightDevice")]
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "weightDevice", default)]
     weight_device: Vec<LinuxWeightDevice>,
     /// IO read rate limit per cgroup per device, bytes per second
     #[serde(
         skip_serializing_if = "Vec::is_empty",
-        rename = "throttleReadBpsDevice"
+        rename = "throttleReadBpsDevice",
+        default
     )]
     throttle_read_bps_device: Vec<LinuxThrottleDevice>,
     /// IO write rate limit per cgroup per device, bytes per second
     #[serde(
         skip_serializing_if = "Vec::is_empty",
-        rename = "throttleWriteBpsDevice"
+        rename = "throttleWriteBpsDevice",
+        default
     )]
     throttle_write_bps_device: Vec<LinuxThrottleDevice>,
     /// IO read rate limit per cgroup per device, IO per second
     #[serde(
         skip_serializing_if = "Vec::is_empty",
-        rename = "throttleReadIOPSDevice"
+        rename = "throttleReadIOPSDevice",
+        default
     )]
     throttle_read_iops_device: Vec<LinuxThrottleDevice>,
     /// IO write rate limit per cgroup per device, IO per second
     #[serde(
         skip_serializing_if = "Vec::is_empty",
-        rename = "throttleWriteIOPSDevice"
+        rename = "throttleWriteIOPSDevice",
+        default
     )]
     throttle_write_iops_device: Vec<LinuxThrottleDevice>,
 }
 
+impl LinuxBlockIO {
+    /// Specifies per cgroup weight
+    pub fn weight(&self) -> Option<u16> {
+        self.weight
+    }
+
+    /// Specifies tasks' weight in the given cgroup while competing with the cgroup's child cgroups, CFQ scheduler only
+    pub fn leaf_weight(&self) -> Option<u16> {
+        self.leaf_weight
+    }
+
+    /// Weight per cgroup per device, can override BlkioWeight
+    pub fn weight_device(&self) -> &[LinuxWeightDevice] {
+        &self.weight_device
+    }
+
+    /// IO read rate limit per cgroup per device, bytes per second
+    pub fn throttle_read_bps_device(&self) -> &[LinuxThrottleDevice] {
+        &self.throttle_read_bps_device
+    }
+
+    /// IO write rate limit per cgroup per device, bytes per second
+    pub fn throttle_write_bps_device(&self) -> &[LinuxThrottleDevice] {
+        &self.throttle_write_bps_device
+    }
+
+    /// IO read rate limit per cgroup per device, IO per second
+    pub fn throttle_read_iops_device(&self) -> &[LinuxThrottleDevice] {
+        &self.throttle_read_iops_device
+    }
+
+    /// IO write rate limit per cgroup per device, IO per second
+    pub fn throttle_write_iops_device(&self) -> &[LinuxThrottleDevice] {
+        &self.throttle_write_iops_device
+    }
+
+    /// Mutable access to [`weight`](Self::weight).
+    pub fn weight_mut(&mut self) -> &mut Option<u16> {
+        &mut self.weight
+    }
+
+    /// Mutable access to [`leaf_weight`](Self::leaf_weight).
+    pub fn leaf_weight_mut(&mut self) -> &mut Option<u16> {
+        &mut self.leaf_weight
+    }
+
+    /// Mutable access to [`weight_device`](Self::weight_device).
+    pub fn weight_device_mut(&mut self) -> &mut Vec<LinuxWeightDevice> {
+        &mut self.weight_device
+    }
+
+    /// Mutable access to [`throttle_read_bps_device`](Self::throttle_read_bps_device).
+    pub fn throttle_read_bps_device_mut(&mut self) -> &mut Vec<LinuxThrottleDevice> {
+        &mut self.throttle_read_bps_device
+    }
+
+    /// Mutable access to [`throttle_write_bps_device`](Self::throttle_write_bps_device).
+    pub fn throttle_write_bps_device_mut(&mut self) -> &mut Vec<LinuxThrottleDevice> {
+        &mut self.throttle_write_bps_device
+    }
+
+    /// Mutable access to [`throttle_read_iops_device`](Self::throttle_read_iops_device).
+    pub fn throttle_read_iops_device_mut(&mut self) -> &mut Vec<LinuxThrottleDevice> {
+        &mut self.throttle_read_iops_device
+    }
+
+    /// Mutable access to [`throttle_write_iops_device`](Self::throttle_write_iops_device).
+    pub fn throttle_write_iops_device_mut(&mut self) -> &mut Vec<LinuxThrottleDevice> {
+        &mut self.throttle_write_iops_device
+    }
+}
+
 /// LinuxMemory for Linux cgroup 'memory' resource management
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -421,6 +2001,105 @@ pub struct LinuxMemory {
     /// Enables hierarchical memory accounting
     #[serde(skip_serializing_if = "Option::is_none", rename = "useHierarchy")]
     use_hierarchy: Option<bool>,
+    /// CheckBeforeUpdate enables checking if a new memory limit is lower
+    /// than the current usage during update, and if so, rejecting the new
+    /// limit.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "checkBeforeUpdate")]
+    check_before_update: Option<bool>,
+}
+
+impl LinuxMemory {
+    /// Memory limit (in bytes).
+    pub fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+
+    /// Memory reservation or soft_limit (in bytes).
+    pub fn reservation(&self) -> Option<i64> {
+        self.reservation
+    }
+
+    /// Total memory limit (memory + swap).
+    pub fn swap(&self) -> Option<i64> {
+        self.swap
+    }
+
+    /// Kernel memory limit (in bytes).
+    pub fn kernel(&self) -> Option<i64> {
+        self.kernel
+    }
+
+    /// Kernel memory limit for tcp (in bytes)
+    pub fn kernel_tcp(&self) -> Option<i64> {
+        self.kernel_tcp
+    }
+
+    /// How aggressive the kernel will swap memory pages.
+    pub fn swappiness(&self) -> Option<i64> {
+        self.swappiness
+    }
+
+    /// DisableOOMKiller disables the OOM killer for out of memory conditions
+    pub fn disable_oom_killer(&self) -> Option<bool> {
+        self.disable_oom_killer
+    }
+
+    /// Enables hierarchical memory accounting
+    pub fn use_hierarchy(&self) -> Option<bool> {
+        self.use_hierarchy
+    }
+
+    /// CheckBeforeUpdate enables checking if a new memory limit is lower
+    /// than the current usage during update, and if so, rejecting the new
+    /// limit.
+    pub fn check_before_update(&self) -> Option<bool> {
+        self.check_before_update
+    }
+
+    /// Mutable access to [`limit`](Self::limit).
+    pub fn limit_mut(&mut self) -> &mut Option<i64> {
+        &mut self.limit
+    }
+
+    /// Mutable access to [`reservation`](Self::reservation).
+    pub fn reservation_mut(&mut self) -> &mut Option<i64> {
+        &mut self.reservation
+    }
+
+    /// Mutable access to [`swap`](Self::swap).
+    pub fn swap_mut(&mut self) -> &mut Option<i64> {
+        &mut self.swap
+    }
+
+    /// Mutable access to [`kernel`](Self::kernel).
+    pub fn kernel_mut(&mut self) -> &mut Option<i64> {
+        &mut self.kernel
+    }
+
+    /// Mutable access to [`kernel_tcp`](Self::kernel_tcp).
+    pub fn kernel_tcp_mut(&mut self) -> &mut Option<i64> {
+        &mut self.kernel_tcp
+    }
+
+    /// Mutable access to [`swappiness`](Self::swappiness).
+    pub fn swappiness_mut(&mut self) -> &mut Option<i64> {
+        &mut self.swappiness
+    }
+
+    /// Mutable access to [`disable_oom_killer`](Self::disable_oom_killer).
+    pub fn disable_oom_killer_mut(&mut self) -> &mut Option<bool> {
+        &mut self.disable_oom_killer
+    }
+
+    /// Mutable access to [`use_hierarchy`](Self::use_hierarchy).
+    pub fn use_hierarchy_mut(&mut self) -> &mut Option<bool> {
+        &mut self.use_hierarchy
+    }
+
+    /// Mutable access to [`check_before_update`](Self::check_before_update).
+    pub fn check_before_update_mut(&mut self) -> &mut Option<bool> {
+        &mut self.check_before_update
+    }
 }
 
 /// LinuxCPU for Linux cgroup 'cpu' resource management
@@ -448,17 +2127,130 @@ pub struct LinuxCPU {
     /// List of memory nodes in the cpuset. Default is to use any available memory node.
     #[serde(skip_serializing_if = "Option::is_none")]
     mems: Option<String>,
+    /// CPU hardcap burst limit (in usecs). Allowed accumulated cpu time additionally for burst in a
+    /// given period.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    burst: Option<u64>,
+    /// cgroups are configured with minimum weight, 0: default behavior, 1: SCHED_IDLE.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    idle: Option<i64>,
 }
 
-/// LinuxPids for Linux cgroup 'pids' resource management (Linux 4.3)
-#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
-#[builder(default, setter(into))]
-pub struct LinuxPids {
-    /// Maximum number of PIDs. Default is "no limit".
-    limit: i64,
-}
+impl LinuxCPU {
+    /// CPU shares (relative weight (ratio) vs. other cgroups with cpu shares).
+    pub fn shares(&self) -> Option<u64> {
+        self.shares
+    }
 
-/// LinuxNetwork identification and priority configuration
+    /// CPU hardcap limit (in usecs). Allowed cpu time in a given period.
+    pub fn quota(&self) -> Option<i64> {
+        self.quota
+    }
+
+    /// CPU period to be used for hardcapping (in usecs).
+    pub fn period(&self) -> Option<u64> {
+        self.period
+    }
+
+    /// How much time realtime scheduling may use (in usecs).
+    pub fn realtime_runtime(&self) -> Option<i64> {
+        self.realtime_runtime
+    }
+
+    /// CPU period to be used for realtime scheduling (in usecs).
+    pub fn realtime_period(&self) -> Option<u64> {
+        self.realtime_period
+    }
+
+    /// CPUs to use within the cpuset. Default is to use any CPU available.
+    pub fn cpus(&self) -> Option<&str> {
+        self.cpus.as_deref()
+    }
+
+    /// List of memory nodes in the cpuset. Default is to use any available memory node.
+    pub fn mems(&self) -> Option<&str> {
+        self.mems.as_deref()
+    }
+
+    /// CPU hardcap burst limit (in usecs). Allowed accumulated cpu time additionally for burst in a
+    /// given period.
+    pub fn burst(&self) -> Option<u64> {
+        self.burst
+    }
+
+    /// cgroups are configured with minimum weight, 0: default behavior, 1: SCHED_IDLE.
+    pub fn idle(&self) -> Option<i64> {
+        self.idle
+    }
+
+    /// Mutable access to [`shares`](Self::shares).
+    pub fn shares_mut(&mut self) -> &mut Option<u64> {
+        &mut self.shares
+    }
+
+    /// Mutable access to [`quota`](Self::quota).
+    pub fn quota_mut(&mut self) -> &mut Option<i64> {
+        &mut self.quota
+    }
+
+    /// Mutable access to [`period`](Self::period).
+    pub fn period_mut(&mut self) -> &mut Option<u64> {
+        &mut self.period
+    }
+
+    /// Mutable access to [`realtime_runtime`](Self::realtime_runtime).
+    pub fn realtime_runtime_mut(&mut self) -> &mut Option<i64> {
+        &mut self.realtime_runtime
+    }
+
+    /// Mutable access to [`realtime_period`](Self::realtime_period).
+    pub fn realtime_period_mut(&mut self) -> &mut Option<u64> {
+        &mut self.realtime_period
+    }
+
+    /// Mutable access to [`cpus`](Self::cpus).
+    pub fn cpus_mut(&mut self) -> &mut Option<String> {
+        &mut self.cpus
+    }
+
+    /// Mutable access to [`mems`](Self::mems).
+    pub fn mems_mut(&mut self) -> &mut Option<String> {
+        &mut self.mems
+    }
+
+    /// Mutable access to [`burst`](Self::burst).
+    pub fn burst_mut(&mut self) -> &mut Option<u64> {
+        &mut self.burst
+    }
+
+    /// Mutable access to [`idle`](Self::idle).
+    pub fn idle_mut(&mut self) -> &mut Option<i64> {
+        &mut self.idle
+    }
+}
+
+/// LinuxPids for Linux cgroup 'pids' resource management (Linux 4.3)
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct LinuxPids {
+    /// Maximum number of PIDs. Default is "no limit".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<i64>,
+}
+
+impl LinuxPids {
+    /// Maximum number of PIDs. Default is "no limit".
+    pub fn limit(&self) -> Option<i64> {
+        self.limit
+    }
+
+    /// Mutable access to [`limit`](Self::limit).
+    pub fn limit_mut(&mut self) -> &mut Option<i64> {
+        &mut self.limit
+    }
+}
+
+/// LinuxNetwork identification and priority configuration
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
 pub struct LinuxNetwork {
@@ -466,10 +2258,32 @@ pub struct LinuxNetwork {
     #[serde(skip_serializing_if = "Option::is_none", rename = "classID")]
     class_id: Option<u32>,
     /// Set priority of network traffic for container
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     priorities: Vec<LinuxInterfacePriority>,
 }
 
+impl LinuxNetwork {
+    /// Set class identifier for container's network packets
+    pub fn class_id(&self) -> Option<u32> {
+        self.class_id
+    }
+
+    /// Set priority of network traffic for container
+    pub fn priorities(&self) -> &[LinuxInterfacePriority] {
+        &self.priorities
+    }
+
+    /// Mutable access to [`class_id`](Self::class_id).
+    pub fn class_id_mut(&mut self) -> &mut Option<u32> {
+        &mut self.class_id
+    }
+
+    /// Mutable access to [`priorities`](Self::priorities).
+    pub fn priorities_mut(&mut self) -> &mut Vec<LinuxInterfacePriority> {
+        &mut self.priorities
+    }
+}
+
 /// LinuxRdma for Linux cgroup 'rdma' resource management (Linux 4.11)
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -482,12 +2296,34 @@ pub struct LinuxRdma {
     hca_objects: Option<u32>,
 }
 
+impl LinuxRdma {
+    /// Maximum number of HCA handles that can be opened. Default is "no limit".
+    pub fn hca_handles(&self) -> Option<u32> {
+        self.hca_handles
+    }
+
+    /// Maximum number of HCA objects that can be created. Default is "no limit".
+    pub fn hca_objects(&self) -> Option<u32> {
+        self.hca_objects
+    }
+
+    /// Mutable access to [`hca_handles`](Self::hca_handles).
+    pub fn hca_handles_mut(&mut self) -> &mut Option<u32> {
+        &mut self.hca_handles
+    }
+
+    /// Mutable access to [`hca_objects`](Self::hca_objects).
+    pub fn hca_objects_mut(&mut self) -> &mut Option<u32> {
+        &mut self.hca_objects
+    }
+}
+
 /// LinuxResources has container runtime resource constraints
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
 pub struct LinuxResources {
     /// Devices configures the device whitelist.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     devices: Vec<LinuxDeviceCgroup>,
     /// Memory restriction configuration
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -512,6 +2348,141 @@ pub struct LinuxResources {
     /// where the key is device name and value is resource limits.
     #[serde(skip_serializing_if = "HashMap::is_empty", default)]
     rdma: HashMap<String, LinuxRdma>,
+    /// Unified resources are cgroup v2 interface files written as-is, keyed by file name
+    /// such as `memory.high`.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    unified: HashMap<String, String>,
+}
+
+impl LinuxResources {
+    /// Devices configures the device whitelist.
+    pub fn devices(&self) -> &[LinuxDeviceCgroup] {
+        &self.devices
+    }
+
+    /// Memory restriction configuration
+    pub fn memory(&self) -> Option<&LinuxMemory> {
+        self.memory.as_ref()
+    }
+
+    /// CPU resource restriction configuration
+    pub fn cpu(&self) -> Option<&LinuxCPU> {
+        self.cpu.as_ref()
+    }
+
+    /// Task resource restriction configuration.
+    pub fn pids(&self) -> Option<&LinuxPids> {
+        self.pids.as_ref()
+    }
+
+    /// BlockIO restriction configuration
+    pub fn block_io(&self) -> Option<&LinuxBlockIO> {
+        self.block_io.as_ref()
+    }
+
+    /// Hugetlb limit (in bytes)
+    pub fn hugepage_limits(&self) -> &[LinuxHugepageLimit] {
+        &self.hugepage_limits
+    }
+
+    /// Network restriction configuration
+    pub fn network(&self) -> Option<&LinuxNetwork> {
+        self.network.as_ref()
+    }
+
+    /// Rdma resource restriction configuration.
+    /// Limits are a set of key value pairs that define RDMA resource limits,
+    /// where the key is device name and value is resource limits.
+    pub fn rdma(&self) -> &HashMap<String, LinuxRdma> {
+        &self.rdma
+    }
+
+    /// Unified resources are cgroup v2 interface files written as-is, keyed by file name
+    /// such as `memory.high`.
+    pub fn unified(&self) -> &HashMap<String, String> {
+        &self.unified
+    }
+
+    /// Mutable access to [`devices`](Self::devices).
+    pub fn devices_mut(&mut self) -> &mut Vec<LinuxDeviceCgroup> {
+        &mut self.devices
+    }
+
+    /// Mutable access to [`memory`](Self::memory).
+    pub fn memory_mut(&mut self) -> &mut Option<LinuxMemory> {
+        &mut self.memory
+    }
+
+    /// Mutable access to [`cpu`](Self::cpu).
+    pub fn cpu_mut(&mut self) -> &mut Option<LinuxCPU> {
+        &mut self.cpu
+    }
+
+    /// Mutable access to [`pids`](Self::pids).
+    pub fn pids_mut(&mut self) -> &mut Option<LinuxPids> {
+        &mut self.pids
+    }
+
+    /// Mutable access to [`block_io`](Self::block_io).
+    pub fn block_io_mut(&mut self) -> &mut Option<LinuxBlockIO> {
+        &mut self.block_io
+    }
+
+    /// Mutable access to [`hugepage_limits`](Self::hugepage_limits).
+    pub fn hugepage_limits_mut(&mut self) -> &mut Vec<LinuxHugepageLimit> {
+        &mut self.hugepage_limits
+    }
+
+    /// Mutable access to [`network`](Self::network).
+    pub fn network_mut(&mut self) -> &mut Option<LinuxNetwork> {
+        &mut self.network
+    }
+
+    /// Mutable access to [`rdma`](Self::rdma).
+    pub fn rdma_mut(&mut self) -> &mut HashMap<String, LinuxRdma> {
+        &mut self.rdma
+    }
+
+    /// Mutable access to [`unified`](Self::unified).
+    pub fn unified_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.unified
+    }
+
+    /// Sets the cgroup v2 interface file `key`, e.g. `io.max`, to `value`.
+    pub fn set_unified<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        self.unified.insert(key.into(), value.into());
+    }
+
+    /// Sets `memory.high`, the memory throttling threshold in bytes. `None` means no limit.
+    pub fn set_memory_high(&mut self, bytes: Option<u64>) {
+        self.set_unified("memory.high", limit_or_max(bytes));
+    }
+
+    /// Sets `memory.max`, the hard memory limit in bytes. `None` means no limit.
+    pub fn set_memory_max(&mut self, bytes: Option<u64>) {
+        self.set_unified("memory.max", limit_or_max(bytes));
+    }
+
+    /// Sets `pids.max`, the maximum number of tasks. `None` means no limit.
+    pub fn set_pids_max(&mut self, max: Option<u64>) {
+        self.set_unified("pids.max", limit_or_max(max));
+    }
+
+    /// Sets `cpu.weight`, the relative CPU weight in the range 1 to 10000.
+    /// Values outside that range are reported by [`Spec::validate`].
+    pub fn set_cpu_weight(&mut self, weight: u16) {
+        self.set_unified("cpu.weight", weight.to_string());
+    }
+
+    /// Sets `cpu.weight.nice`, the CPU weight expressed as a nice value from -20 to 19.
+    /// Values outside that range are reported by [`Spec::validate`].
+    pub fn set_cpu_weight_nice(&mut self, nice: i8) {
+        self.set_unified("cpu.weight.nice", nice.to_string());
+    }
+}
+
+fn limit_or_max(limit: Option<u64>) -> String {
+    limit.map_or_else(|| "max".to_string(), |limit| limit.to_string())
 }
 
 /// LinuxDevice represents the mknod information for a Linux special device file
@@ -538,6 +2509,78 @@ pub struct LinuxDevice {
     gid: Option<u32>,
 }
 
+impl LinuxDevice {
+    /// Path to the device.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Device type, block, char, etc.
+    pub fn device_type(&self) -> &str {
+        &self.device_type
+    }
+
+    /// Major is the device's major number.
+    pub fn major(&self) -> i64 {
+        self.major
+    }
+
+    /// Minor is the device's minor number.
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    /// FileMode permission bits for the device.
+    pub fn file_mode(&self) -> Option<u32> {
+        self.file_mode
+    }
+
+    /// UID of the device.
+    pub fn uid(&self) -> Option<u32> {
+        self.uid
+    }
+
+    /// Gid of the device.
+    pub fn gid(&self) -> Option<u32> {
+        self.gid
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`device_type`](Self::device_type).
+    pub fn device_type_mut(&mut self) -> &mut String {
+        &mut self.device_type
+    }
+
+    /// Mutable access to [`major`](Self::major).
+    pub fn major_mut(&mut self) -> &mut i64 {
+        &mut self.major
+    }
+
+    /// Mutable access to [`minor`](Self::minor).
+    pub fn minor_mut(&mut self) -> &mut i64 {
+        &mut self.minor
+    }
+
+    /// Mutable access to [`file_mode`](Self::file_mode).
+    pub fn file_mode_mut(&mut self) -> &mut Option<u32> {
+        &mut self.file_mode
+    }
+
+    /// Mutable access to [`uid`](Self::uid).
+    pub fn uid_mut(&mut self) -> &mut Option<u32> {
+        &mut self.uid
+    }
+
+    /// Mutable access to [`gid`](Self::gid).
+    pub fn gid_mut(&mut self) -> &mut Option<u32> {
+        &mut self.gid
+    }
+}
+
 /// LinuxDeviceCgroup represents a device rule for the whitelist controller
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -558,6 +2601,113 @@ pub struct LinuxDeviceCgroup {
     access: Option<String>,
 }
 
+impl LinuxDeviceCgroup {
+    /// Allow or deny
+    pub fn allow(&self) -> bool {
+        self.allow
+    }
+
+    /// Device type, block, char, etc.
+    pub fn device_type(&self) -> Option<&str> {
+        self.device_type.as_deref()
+    }
+
+    /// Major is the device's major number.
+    pub fn major(&self) -> Option<i64> {
+        self.major
+    }
+
+    /// Minor is the device's minor number.
+    pub fn minor(&self) -> Option<i64> {
+        self.minor
+    }
+
+    /// Cgroup access permissions format, rwm.
+    pub fn access(&self) -> Option<&str> {
+        self.access.as_deref()
+    }
+
+    /// Mutable access to [`allow`](Self::allow).
+    pub fn allow_mut(&mut self) -> &mut bool {
+        &mut self.allow
+    }
+
+    /// Mutable access to [`device_type`](Self::device_type).
+    pub fn device_type_mut(&mut self) -> &mut Option<String> {
+        &mut self.device_type
+    }
+
+    /// Mutable access to [`major`](Self::major).
+    pub fn major_mut(&mut self) -> &mut Option<i64> {
+        &mut self.major
+    }
+
+    /// Mutable access to [`minor`](Self::minor).
+    pub fn minor_mut(&mut self) -> &mut Option<i64> {
+        &mut self.minor
+    }
+
+    /// Mutable access to [`access`](Self::access).
+    pub fn access_mut(&mut self) -> &mut Option<String> {
+        &mut self.access
+    }
+}
+
+/// LinuxNetDevice represents a single network device to be added to the container's network namespace
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct LinuxNetDevice {
+    /// Name of the device in the container namespace. Defaults to the host name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl LinuxNetDevice {
+    /// Name of the device in the container namespace. Defaults to the host name.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Mutable access to [`name`](Self::name).
+    pub fn name_mut(&mut self) -> &mut Option<String> {
+        &mut self.name
+    }
+}
+
+/// LinuxTimeOffset specifies the offset for Time Namespace
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct LinuxTimeOffset {
+    /// Secs is the offset of clock (in secs) in the container
+    #[serde(default)]
+    secs: i64,
+    /// Nanosecs is the additional offset for Secs (in nanosecs)
+    #[serde(default)]
+    nanosecs: u32,
+}
+
+impl LinuxTimeOffset {
+    /// Secs is the offset of clock (in secs) in the container
+    pub fn secs(&self) -> i64 {
+        self.secs
+    }
+
+    /// Nanosecs is the additional offset for Secs (in nanosecs)
+    pub fn nanosecs(&self) -> u32 {
+        self.nanosecs
+    }
+
+    /// Mutable access to [`secs`](Self::secs).
+    pub fn secs_mut(&mut self) -> &mut i64 {
+        &mut self.secs
+    }
+
+    /// Mutable access to [`nanosecs`](Self::nanosecs).
+    pub fn nanosecs_mut(&mut self) -> &mut u32 {
+        &mut self.nanosecs
+    }
+}
+
 /// LinuxPersonality represents the Linux personality syscall input
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
@@ -565,51 +2715,376 @@ pub struct LinuxPersonality {
     /// Domain for the personality
     domain: String,
     /// Additional flags
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     flags: Vec<String>,
 }
 
+impl LinuxPersonality {
+    /// Domain for the personality
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// Additional flags
+    pub fn flags(&self) -> &[String] {
+        &self.flags
+    }
+
+    /// Mutable access to [`domain`](Self::domain).
+    pub fn domain_mut(&mut self) -> &mut String {
+        &mut self.domain
+    }
+
+    /// Mutable access to [`flags`](Self::flags).
+    pub fn flags_mut(&mut self) -> &mut Vec<String> {
+        &mut self.flags
+    }
+}
+
 /// LinuxSeccomp represents syscall restrictions
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
 pub struct LinuxSeccomp {
+    /// DefaultAction is the action taken for syscalls not matched by any rule.
     #[serde(rename = "defaultAction")]
-    default_action: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    architectures: Vec<String>,
+    default_action: LinuxSeccompAction,
+    /// DefaultErrnoRet is the errno returned by the default action when it is
+    /// SCMP_ACT_ERRNO or SCMP_ACT_TRACE.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "defaultErrnoRet")]
+    default_errno_ret: Option<u32>,
+    /// Architectures are the additional architectures the filter applies to.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    architectures: Vec<Arch>,
+    /// Flags are the seccomp filter flags passed to seccomp(2).
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    flags: Vec<LinuxSeccompFlag>,
+    /// ListenerPath is the path of the UNIX socket the seccomp notify file
+    /// descriptor is sent to when any rule uses SCMP_ACT_NOTIFY.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "listenerPath")]
+    listener_path: Option<String>,
+    /// ListenerMetadata is opaque data passed to the seccomp agent along with
+    /// the notify file descriptor.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "listenerMetadata")]
+    listener_metadata: Option<String>,
+    /// Syscalls are the rules matching specific syscalls.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    flags: Vec<String>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
     syscalls: Vec<LinuxSyscall>,
 }
 
+impl LinuxSeccomp {
+    /// DefaultAction is the action taken for syscalls not matched by any rule.
+    pub fn default_action(&self) -> LinuxSeccompAction {
+        self.default_action
+    }
+
+    /// Architectures are the additional architectures the filter applies to.
+    pub fn architectures(&self) -> &[Arch] {
+        &self.architectures
+    }
+
+    /// Flags are the seccomp filter flags passed to seccomp(2).
+    pub fn flags(&self) -> &[LinuxSeccompFlag] {
+        &self.flags
+    }
+
+    /// Syscalls are the rules matching specific syscalls.
+    pub fn syscalls(&self) -> &[LinuxSyscall] {
+        &self.syscalls
+    }
+
+    /// DefaultErrnoRet is the errno returned by the default action when it is
+    /// SCMP_ACT_ERRNO or SCMP_ACT_TRACE.
+    pub fn default_errno_ret(&self) -> Option<u32> {
+        self.default_errno_ret
+    }
+
+    /// ListenerPath is the path of the UNIX socket the seccomp notify file
+    /// descriptor is sent to when any rule uses SCMP_ACT_NOTIFY.
+    pub fn listener_path(&self) -> Option<&str> {
+        self.listener_path.as_deref()
+    }
+
+    /// ListenerMetadata is opaque data passed to the seccomp agent along with
+    /// the notify file descriptor.
+    pub fn listener_metadata(&self) -> Option<&str> {
+        self.listener_metadata.as_deref()
+    }
+
+    /// Mutable access to [`default_action`](Self::default_action).
+    pub fn default_action_mut(&mut self) -> &mut LinuxSeccompAction {
+        &mut self.default_action
+    }
+
+    /// Mutable access to [`architectures`](Self::architectures).
+    pub fn architectures_mut(&mut self) -> &mut Vec<Arch> {
+        &mut self.architectures
+    }
+
+    /// Mutable access to [`flags`](Self::flags).
+    pub fn flags_mut(&mut self) -> &mut Vec<LinuxSeccompFlag> {
+        &mut self.flags
+    }
+
+    /// Mutable access to [`syscalls`](Self::syscalls).
+    pub fn syscalls_mut(&mut self) -> &mut Vec<LinuxSyscall> {
+        &mut self.syscalls
+    }
+
+    /// Mutable access to [`default_errno_ret`](Self::default_errno_ret).
+    pub fn default_errno_ret_mut(&mut self) -> &mut Option<u32> {
+        &mut self.default_errno_ret
+    }
+
+    /// Mutable access to [`listener_path`](Self::listener_path).
+    pub fn listener_path_mut(&mut self) -> &mut Option<String> {
+        &mut self.listener_path
+    }
+
+    /// Mutable access to [`listener_metadata`](Self::listener_metadata).
+    pub fn listener_metadata_mut(&mut self) -> &mut Option<String> {
+        &mut self.listener_metadata
+    }
+}
+
 /// LinuxSeccompArg used for matching specific syscall arguments in Seccomp
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
 pub struct LinuxSeccompArg {
+    /// Index is the index of the syscall argument.
     index: u64,
+    /// Value is the value the argument is compared against.
     value: u64,
+    /// ValueTwo is the second value, used by SCMP_CMP_MASKED_EQ: the argument masked with
+    /// Value must equal ValueTwo. A missing value means 0.
     #[serde(skip_serializing_if = "Option::is_none", rename = "valueTwo")]
     value_two: Option<u64>,
-    op: String,
+    /// Op is the comparison operator.
+    op: LinuxSeccompOperator,
+}
+
+impl LinuxSeccompArg {
+    /// Index is the index of the syscall argument.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Value is the value the argument is compared against.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// ValueTwo is the second value, used by SCMP_CMP_MASKED_EQ: the argument masked with
+    /// Value must equal ValueTwo. A missing value means 0.
+    pub fn value_two(&self) -> Option<u64> {
+        self.value_two
+    }
+
+    /// Op is the comparison operator.
+    pub fn op(&self) -> LinuxSeccompOperator {
+        self.op
+    }
+
+    /// Mutable access to [`index`](Self::index).
+    pub fn index_mut(&mut self) -> &mut u64 {
+        &mut self.index
+    }
+
+    /// Mutable access to [`value`](Self::value).
+    pub fn value_mut(&mut self) -> &mut u64 {
+        &mut self.value
+    }
+
+    /// Mutable access to [`value_two`](Self::value_two).
+    pub fn value_two_mut(&mut self) -> &mut Option<u64> {
+        &mut self.value_two
+    }
+
+    /// Mutable access to [`op`](Self::op).
+    pub fn op_mut(&mut self) -> &mut LinuxSeccompOperator {
+        &mut self.op
+    }
 }
 
 /// LinuxSyscall is used to match a syscall in Seccomp
 #[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
 #[builder(default, setter(into))]
 pub struct LinuxSyscall {
+    /// Names are the syscalls matched by this rule.
     names: Vec<String>,
-    action: String,
+    /// Action is the action taken when the rule matches.
+    action: LinuxSeccompAction,
+    /// ErrnoRet is the errno returned when the action is SCMP_ACT_ERRNO or
+    /// SCMP_ACT_TRACE.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "errnoRet")]
+    errno_ret: Option<u32>,
+    /// Args restricts the rule to specific syscall arguments.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    args: Vec<LinuxSyscallArg>,
+    args: Vec<LinuxSeccompArg>,
 }
 
-#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
-#[builder(default, setter(into))]
-pub struct LinuxSyscallArg {
-    index: u64,
-    value: u64,
-    op: String
+impl LinuxSyscall {
+    /// Names are the syscalls matched by this rule.
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Action is the action taken when the rule matches.
+    pub fn action(&self) -> LinuxSeccompAction {
+        self.action
+    }
+
+    /// Args restricts the rule to specific syscall arguments.
+    pub fn args(&self) -> &[LinuxSeccompArg] {
+        &self.args
+    }
+
+    /// ErrnoRet is the errno returned when the action is SCMP_ACT_ERRNO or
+    /// SCMP_ACT_TRACE.
+    pub fn errno_ret(&self) -> Option<u32> {
+        self.errno_ret
+    }
+
+    /// Mutable access to [`names`](Self::names).
+    pub fn names_mut(&mut self) -> &mut Vec<String> {
+        &mut self.names
+    }
+
+    /// Mutable access to [`action`](Self::action).
+    pub fn action_mut(&mut self) -> &mut LinuxSeccompAction {
+        &mut self.action
+    }
+
+    /// Mutable access to [`args`](Self::args).
+    pub fn args_mut(&mut self) -> &mut Vec<LinuxSeccompArg> {
+        &mut self.args
+    }
+
+    /// Mutable access to [`errno_ret`](Self::errno_ret).
+    pub fn errno_ret_mut(&mut self) -> &mut Option<u32> {
+        &mut self.errno_ret
+    }
+}
+
+/// LinuxSyscallArg is the former name of [`LinuxSeccompArg`].
+#[deprecated(note = "use `LinuxSeccompArg`, which also supports `valueTwo`")]
+pub type LinuxSyscallArg = LinuxSeccompArg;
+
+/// Builder for [`LinuxSyscallArg`].
+#[deprecated(note = "use `LinuxSeccompArgBuilder`")]
+pub type LinuxSyscallArgBuilder = LinuxSeccompArgBuilder;
+
+string_enum! {
+    /// LinuxSeccompAction taken upon Seccomp rule match
+    #[derive(Default)]
+    pub enum LinuxSeccompAction {
+        /// Kill the thread, same as SCMP_ACT_KILL_THREAD.
+        Kill = "SCMP_ACT_KILL",
+        /// Kill the whole process.
+        KillProcess = "SCMP_ACT_KILL_PROCESS",
+        /// Kill the calling thread.
+        KillThread = "SCMP_ACT_KILL_THREAD",
+        /// Send SIGSYS to the calling thread.
+        Trap = "SCMP_ACT_TRAP",
+        /// Fail the syscall with an errno.
+        Errno = "SCMP_ACT_ERRNO",
+        /// Notify a ptrace tracer.
+        Trace = "SCMP_ACT_TRACE",
+        /// Allow the syscall.
+        #[default]
+        Allow = "SCMP_ACT_ALLOW",
+        /// Allow the syscall and log it.
+        Log = "SCMP_ACT_LOG",
+        /// Forward the syscall to a user-space supervisor.
+        Notify = "SCMP_ACT_NOTIFY",
+    }
+}
+
+string_enum! {
+    /// LinuxSeccompOperator used to match syscall arguments in Seccomp
+    #[derive(Default)]
+    pub enum LinuxSeccompOperator {
+        /// Argument is not equal to the value.
+        NotEqual = "SCMP_CMP_NE",
+        /// Argument is less than the value.
+        LessThan = "SCMP_CMP_LT",
+        /// Argument is less than or equal to the value.
+        LessOrEqual = "SCMP_CMP_LE",
+        /// Argument is equal to the value.
+        #[default]
+        Equal = "SCMP_CMP_EQ",
+        /// Argument is greater than or equal to the value.
+        GreaterOrEqual = "SCMP_CMP_GE",
+        /// Argument is greater than the value.
+        GreaterThan = "SCMP_CMP_GT",
+        /// Argument masked with the value equals valueTwo.
+        MaskedEqual = "SCMP_CMP_MASKED_EQ",
+    }
+}
+
+string_enum! {
+    /// Arch used for additional architectures in Seccomp
+    pub enum Arch {
+        /// 32-bit x86.
+        X86 = "SCMP_ARCH_X86",
+        /// 64-bit x86.
+        X86_64 = "SCMP_ARCH_X86_64",
+        /// x86-64 with 32-bit pointers (x32 ABI).
+        X32 = "SCMP_ARCH_X32",
+        /// 32-bit ARM.
+        Arm = "SCMP_ARCH_ARM",
+        /// 64-bit ARM.
+        Aarch64 = "SCMP_ARCH_AARCH64",
+        /// 32-bit big-endian MIPS.
+        Mips = "SCMP_ARCH_MIPS",
+        /// 64-bit big-endian MIPS.
+        Mips64 = "SCMP_ARCH_MIPS64",
+        /// 64-bit big-endian MIPS with the n32 ABI.
+        Mips64N32 = "SCMP_ARCH_MIPS64N32",
+        /// 32-bit little-endian MIPS.
+        Mipsel = "SCMP_ARCH_MIPSEL",
+        /// 64-bit little-endian MIPS.
+        Mipsel64 = "SCMP_ARCH_MIPSEL64",
+        /// 64-bit little-endian MIPS with the n32 ABI.
+        Mipsel64N32 = "SCMP_ARCH_MIPSEL64N32",
+        /// 32-bit PowerPC.
+        Ppc = "SCMP_ARCH_PPC",
+        /// 64-bit big-endian PowerPC.
+        Ppc64 = "SCMP_ARCH_PPC64",
+        /// 64-bit little-endian PowerPC.
+        Ppc64Le = "SCMP_ARCH_PPC64LE",
+        /// 31-bit IBM S/390.
+        S390 = "SCMP_ARCH_S390",
+        /// 64-bit IBM z/Architecture.
+        S390X = "SCMP_ARCH_S390X",
+        /// 32-bit PA-RISC.
+        Parisc = "SCMP_ARCH_PARISC",
+        /// 64-bit PA-RISC.
+        Parisc64 = "SCMP_ARCH_PARISC64",
+        /// 64-bit RISC-V.
+        Riscv64 = "SCMP_ARCH_RISCV64",
+        /// 64-bit LoongArch.
+        Loongarch64 = "SCMP_ARCH_LOONGARCH64",
+        /// Motorola 68000.
+        M68k = "SCMP_ARCH_M68K",
+        /// Little-endian SuperH.
+        Sh = "SCMP_ARCH_SH",
+        /// Big-endian SuperH.
+        Sheb = "SCMP_ARCH_SHEB",
+    }
+}
+
+string_enum! {
+    /// LinuxSeccompFlag is a flag to pass to seccomp(2).
+    pub enum LinuxSeccompFlag {
+        /// Synchronize the filter across all threads.
+        Tsync = "SECCOMP_FILTER_FLAG_TSYNC",
+        /// Log all filter actions except SCMP_ACT_ALLOW.
+        Log = "SECCOMP_FILTER_FLAG_LOG",
+        /// Disable the Speculative Store Bypass mitigation.
+        SpecAllow = "SECCOMP_FILTER_FLAG_SPEC_ALLOW",
+        /// Wait in a killable state for user notification replies.
+        WaitKillableRecv = "SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV",
+    }
 }
 
 /// LinuxIntelRdt has container runtime resource constraints for Intel RDT
@@ -630,4 +3105,2010 @@ pub struct LinuxIntelRdt {
     /// Default, Clone, and in "MBps" if MBA Software Controller is enabled.
     #[serde(skip_serializing_if = "Option::is_none", rename = "memBwSchema")]
     mem_bw_schema: Option<String>,
+    /// Schemata specifies the complete schemata to be written as is to the
+    /// schemata file in resctrl fs. Each element represents a single line in the
+    /// schemata file.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    schemata: Vec<String>,
+    /// EnableCMT is the flag to indicate if the Intel RDT CMT is enabled. CMT (Cache Monitoring Technology) supports monitoring of
+    /// the last-level cache (LLC) occupancy for the container.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "enableCMT")]
+    enable_cmt: Option<bool>,
+    /// EnableMBM is the flag to indicate if the Intel RDT MBM is enabled. MBM (Memory Bandwidth Monitoring) supports monitoring of
+    /// total and local memory bandwidth for the container.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "enableMBM")]
+    enable_mbm: Option<bool>,
+}
+
+impl LinuxIntelRdt {
+    /// The identity for RDT Class of Service
+    pub fn clos_id(&self) -> Option<&str> {
+        self.clos_id.as_deref()
+    }
+
+    /// The schema for L3 cache id and capacity bitmask (CBM)
+    /// Format: "L3:<cache_id0>=<cbm0>;<cache_id1>=<cbm1>;..."
+    pub fn l3_cache_schema(&self) -> Option<&str> {
+        self.l3_cache_schema.as_deref()
+    }
+
+    /// The schema of memory bandwidth per L3 cache id
+    /// Format: "MB:<cache_id0>=bandwidth0;<cache_id1>=bandwidth1;..."
+    /// The unit of memory bandwidth is specified in "percentages" by
+    /// Default, Clone, and in "MBps" if MBA Software Controller is enabled.
+    pub fn mem_bw_schema(&self) -> Option<&str> {
+        self.mem_bw_schema.as_deref()
+    }
+
+    /// Schemata specifies the complete schemata to be written as is to the
+    /// schemata file in resctrl fs. Each element represents a single line in the
+    /// schemata file.
+    pub fn schemata(&self) -> &[String] {
+        &self.schemata
+    }
+
+    /// EnableCMT is the flag to indicate if the Intel RDT CMT is enabled. CMT (Cache Monitoring Technology) supports monitoring of
+    /// the last-level cache (LLC) occupancy for the container.
+    pub fn enable_cmt(&self) -> Option<bool> {
+        self.enable_cmt
+    }
+
+    /// EnableMBM is the flag to indicate if the Intel RDT MBM is enabled. MBM (Memory Bandwidth Monitoring) supports monitoring of
+    /// total and local memory bandwidth for the container.
+    pub fn enable_mbm(&self) -> Option<bool> {
+        self.enable_mbm
+    }
+
+    /// Mutable access to [`clos_id`](Self::clos_id).
+    pub fn clos_id_mut(&mut self) -> &mut Option<String> {
+        &mut self.clos_id
+    }
+
+    /// Mutable access to [`l3_cache_schema`](Self::l3_cache_schema).
+    pub fn l3_cache_schema_mut(&mut self) -> &mut Option<String> {
+        &mut self.l3_cache_schema
+    }
+
+    /// Mutable access to [`mem_bw_schema`](Self::mem_bw_schema).
+    pub fn mem_bw_schema_mut(&mut self) -> &mut Option<String> {
+        &mut self.mem_bw_schema
+    }
+
+    /// Mutable access to [`schemata`](Self::schemata).
+    pub fn schemata_mut(&mut self) -> &mut Vec<String> {
+        &mut self.schemata
+    }
+
+    /// Mutable access to [`enable_cmt`](Self::enable_cmt).
+    pub fn enable_cmt_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enable_cmt
+    }
+
+    /// Mutable access to [`enable_mbm`](Self::enable_mbm).
+    pub fn enable_mbm_mut(&mut self) -> &mut Option<bool> {
+        &mut self.enable_mbm
+    }
+}
+
+/// Windows defines the runtime configuration for Windows based containers, including Hyper-V containers.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Windows {
+    /// LayerFolders contains a list of absolute paths to directories containing image layers.
+    #[serde(rename = "layerFolders", default)]
+    layer_folders: Vec<String>,
+    /// Devices are the list of devices to be mapped into the container.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    devices: Vec<WindowsDevice>,
+    /// Resources contains information for handling resource constraints for the container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resources: Option<WindowsResources>,
+    /// CredentialSpec contains a JSON object describing a group Managed Service Account (gMSA) specification.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "credentialSpec")]
+    credential_spec: Option<serde_json::Value>,
+    /// Servicing indicates if the container is being started in a mode to apply a Windows Update servicing operation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    servicing: Option<bool>,
+    /// IgnoreFlushesDuringBoot indicates if the container is being started in a mode where disk writes are not flushed during its boot process.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ignoreFlushesDuringBoot")]
+    ignore_flushes_during_boot: Option<bool>,
+    /// HyperV contains information for running a container with Hyper-V isolation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hyperv: Option<WindowsHyperV>,
+    /// Network restriction configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network: Option<WindowsNetwork>,
+}
+
+impl Windows {
+    /// LayerFolders contains a list of absolute paths to directories containing image layers.
+    pub fn layer_folders(&self) -> &[String] {
+        &self.layer_folders
+    }
+
+    /// Devices are the list of devices to be mapped into the container.
+    pub fn devices(&self) -> &[WindowsDevice] {
+        &self.devices
+    }
+
+    /// Resources contains information for handling resource constraints for the container.
+    pub fn resources(&self) -> Option<&WindowsResources> {
+        self.resources.as_ref()
+    }
+
+    /// CredentialSpec contains a JSON object describing a group Managed Service Account (gMSA) specification.
+    pub fn credential_spec(&self) -> Option<&serde_json::Value> {
+        self.credential_spec.as_ref()
+    }
+
+    /// Servicing indicates if the container is being started in a mode to apply a Windows Update servicing operation.
+    pub fn servicing(&self) -> Option<bool> {
+        self.servicing
+    }
+
+    /// IgnoreFlushesDuringBoot indicates if the container is being started in a mode where disk writes are not flushed during its boot process.
+    pub fn ignore_flushes_during_boot(&self) -> Option<bool> {
+        self.ignore_flushes_during_boot
+    }
+
+    /// HyperV contains information for running a container with Hyper-V isolation.
+    pub fn hyperv(&self) -> Option<&WindowsHyperV> {
+        self.hyperv.as_ref()
+    }
+
+    /// Network restriction configuration.
+    pub fn network(&self) -> Option<&WindowsNetwork> {
+        self.network.as_ref()
+    }
+
+    /// Mutable access to [`layer_folders`](Self::layer_folders).
+    pub fn layer_folders_mut(&mut self) -> &mut Vec<String> {
+        &mut self.layer_folders
+    }
+
+    /// Mutable access to [`devices`](Self::devices).
+    pub fn devices_mut(&mut self) -> &mut Vec<WindowsDevice> {
+        &mut self.devices
+    }
+
+    /// Mutable access to [`resources`](Self::resources).
+    pub fn resources_mut(&mut self) -> &mut Option<WindowsResources> {
+        &mut self.resources
+    }
+
+    /// Mutable access to [`credential_spec`](Self::credential_spec).
+    pub fn credential_spec_mut(&mut self) -> &mut Option<serde_json::Value> {
+        &mut self.credential_spec
+    }
+
+    /// Mutable access to [`servicing`](Self::servicing).
+    pub fn servicing_mut(&mut self) -> &mut Option<bool> {
+        &mut self.servicing
+    }
+
+    /// Mutable access to [`ignore_flushes_during_boot`](Self::ignore_flushes_during_boot).
+    pub fn ignore_flushes_during_boot_mut(&mut self) -> &mut Option<bool> {
+        &mut self.ignore_flushes_during_boot
+    }
+
+    /// Mutable access to [`hyperv`](Self::hyperv).
+    pub fn hyperv_mut(&mut self) -> &mut Option<WindowsHyperV> {
+        &mut self.hyperv
+    }
+
+    /// Mutable access to [`network`](Self::network).
+    pub fn network_mut(&mut self) -> &mut Option<WindowsNetwork> {
+        &mut self.network
+    }
+}
+
+/// WindowsDevice represents information about a host device to be mapped into the container.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct WindowsDevice {
+    /// Device identifier: interface class GUID, etc.
+    id: String,
+    /// Device identifier type: "class", etc.
+    #[serde(rename = "idType")]
+    id_type: String,
+}
+
+impl WindowsDevice {
+    /// Device identifier: interface class GUID, etc.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Device identifier type: "class", etc.
+    pub fn id_type(&self) -> &str {
+        &self.id_type
+    }
+
+    /// Mutable access to [`id`](Self::id).
+    pub fn id_mut(&mut self) -> &mut String {
+        &mut self.id
+    }
+
+    /// Mutable access to [`id_type`](Self::id_type).
+    pub fn id_type_mut(&mut self) -> &mut String {
+        &mut self.id_type
+    }
+}
+
+/// WindowsResources has container runtime resource constraints for containers running on Windows.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct WindowsResources {
+    /// Memory restriction configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory: Option<WindowsMemoryResources>,
+    /// CPU resource restriction configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cpu: Option<WindowsCPUResources>,
+    /// Storage restriction configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    storage: Option<WindowsStorageResources>,
+}
+
+impl WindowsResources {
+    /// Memory restriction configuration.
+    pub fn memory(&self) -> Option<&WindowsMemoryResources> {
+        self.memory.as_ref()
+    }
+
+    /// CPU resource restriction configuration.
+    pub fn cpu(&self) -> Option<&WindowsCPUResources> {
+        self.cpu.as_ref()
+    }
+
+    /// Storage restriction configuration.
+    pub fn storage(&self) -> Option<&WindowsStorageResources> {
+        self.storage.as_ref()
+    }
+
+    /// Mutable access to [`memory`](Self::memory).
+    pub fn memory_mut(&mut self) -> &mut Option<WindowsMemoryResources> {
+        &mut self.memory
+    }
+
+    /// Mutable access to [`cpu`](Self::cpu).
+    pub fn cpu_mut(&mut self) -> &mut Option<WindowsCPUResources> {
+        &mut self.cpu
+    }
+
+    /// Mutable access to [`storage`](Self::storage).
+    pub fn storage_mut(&mut self) -> &mut Option<WindowsStorageResources> {
+        &mut self.storage
+    }
+}
+
+/// WindowsMemoryResources contains memory resource management settings.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct WindowsMemoryResources {
+    /// Memory limit in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u64>,
+}
+
+impl WindowsMemoryResources {
+    /// Memory limit in bytes.
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    /// Mutable access to [`limit`](Self::limit).
+    pub fn limit_mut(&mut self) -> &mut Option<u64> {
+        &mut self.limit
+    }
+}
+
+/// WindowsCPUResources contains CPU resource management settings.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct WindowsCPUResources {
+    /// Number of CPUs available to the container.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<u64>,
+    /// CPU shares (relative weight to other containers with cpu shares).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shares: Option<u16>,
+    /// Specifies the portion of processor cycles that this container can use as a percentage times 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maximum: Option<u16>,
+}
+
+impl WindowsCPUResources {
+    /// Number of CPUs available to the container.
+    pub fn count(&self) -> Option<u64> {
+        self.count
+    }
+
+    /// CPU shares (relative weight to other containers with cpu shares).
+    pub fn shares(&self) -> Option<u16> {
+        self.shares
+    }
+
+    /// Specifies the portion of processor cycles that this container can use as a percentage times 100.
+    pub fn maximum(&self) -> Option<u16> {
+        self.maximum
+    }
+
+    /// Mutable access to [`count`](Self::count).
+    pub fn count_mut(&mut self) -> &mut Option<u64> {
+        &mut self.count
+    }
+
+    /// Mutable access to [`shares`](Self::shares).
+    pub fn shares_mut(&mut self) -> &mut Option<u16> {
+        &mut self.shares
+    }
+
+    /// Mutable access to [`maximum`](Self::maximum).
+    pub fn maximum_mut(&mut self) -> &mut Option<u16> {
+        &mut self.maximum
+    }
+}
+
+/// WindowsStorageResources contains storage resource management settings.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct WindowsStorageResources {
+    /// Specifies maximum Iops for the system drive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iops: Option<u64>,
+    /// Specifies maximum bytes per second for the system drive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bps: Option<u64>,
+    /// Sandbox size specifies the minimum size of the system drive in bytes.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sandboxSize")]
+    sandbox_size: Option<u64>,
+}
+
+impl WindowsStorageResources {
+    /// Specifies maximum Iops for the system drive.
+    pub fn iops(&self) -> Option<u64> {
+        self.iops
+    }
+
+    /// Specifies maximum bytes per second for the system drive.
+    pub fn bps(&self) -> Option<u64> {
+        self.bps
+    }
+
+    /// Sandbox size specifies the minimum size of the system drive in bytes.
+    pub fn sandbox_size(&self) -> Option<u64> {
+        self.sandbox_size
+    }
+
+    /// Mutable access to [`iops`](Self::iops).
+    pub fn iops_mut(&mut self) -> &mut Option<u64> {
+        &mut self.iops
+    }
+
+    /// Mutable access to [`bps`](Self::bps).
+    pub fn bps_mut(&mut self) -> &mut Option<u64> {
+        &mut self.bps
+    }
+
+    /// Mutable access to [`sandbox_size`](Self::sandbox_size).
+    pub fn sandbox_size_mut(&mut self) -> &mut Option<u64> {
+        &mut self.sandbox_size
+    }
+}
+
+/// WindowsNetwork contains network settings for Windows containers.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct WindowsNetwork {
+    /// List of HNS endpoints that the container should connect to.
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "endpointList", default)]
+    endpoint_list: Vec<String>,
+    /// Specifies if unqualified DNS name resolution is allowed.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "allowUnqualifiedDNSQuery"
+    )]
+    allow_unqualified_dns_query: Option<bool>,
+    /// Comma separated list of DNS suffixes to use for name resolution.
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "DNSSearchList", default)]
+    dns_search_list: Vec<String>,
+    /// Name (ID) of the container that we will share with the network stack.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "networkSharedContainerName"
+    )]
+    network_shared_container_name: Option<String>,
+    /// name (ID) of the network namespace that will be used for the container.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "networkNamespace")]
+    network_namespace: Option<String>,
+}
+
+impl WindowsNetwork {
+    /// List of HNS endpoints that the container should connect to.
+    pub fn endpoint_list(&self) -> &[String] {
+        &self.endpoint_list
+    }
+
+    /// Specifies if unqualified DNS name resolution is allowed.
+    pub fn allow_unqualified_dns_query(&self) -> Option<bool> {
+        self.allow_unqualified_dns_query
+    }
+
+    /// Comma separated list of DNS suffixes to use for name resolution.
+    pub fn dns_search_list(&self) -> &[String] {
+        &self.dns_search_list
+    }
+
+    /// Name (ID) of the container that we will share with the network stack.
+    pub fn network_shared_container_name(&self) -> Option<&str> {
+        self.network_shared_container_name.as_deref()
+    }
+
+    /// name (ID) of the network namespace that will be used for the container.
+    pub fn network_namespace(&self) -> Option<&str> {
+        self.network_namespace.as_deref()
+    }
+
+    /// Mutable access to [`endpoint_list`](Self::endpoint_list).
+    pub fn endpoint_list_mut(&mut self) -> &mut Vec<String> {
+        &mut self.endpoint_list
+    }
+
+    /// Mutable access to [`allow_unqualified_dns_query`](Self::allow_unqualified_dns_query).
+    pub fn allow_unqualified_dns_query_mut(&mut self) -> &mut Option<bool> {
+        &mut self.allow_unqualified_dns_query
+    }
+
+    /// Mutable access to [`dns_search_list`](Self::dns_search_list).
+    pub fn dns_search_list_mut(&mut self) -> &mut Vec<String> {
+        &mut self.dns_search_list
+    }
+
+    /// Mutable access to [`network_shared_container_name`](Self::network_shared_container_name).
+    pub fn network_shared_container_name_mut(&mut self) -> &mut Option<String> {
+        &mut self.network_shared_container_name
+    }
+
+    /// Mutable access to [`network_namespace`](Self::network_namespace).
+    pub fn network_namespace_mut(&mut self) -> &mut Option<String> {
+        &mut self.network_namespace
+    }
+}
+
+/// WindowsHyperV contains information for configuring a container to run with Hyper-V isolation.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct WindowsHyperV {
+    /// UtilityVMPath is an optional path to the image used for the Utility VM.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "utilityVMPath")]
+    utility_vm_path: Option<String>,
+}
+
+impl WindowsHyperV {
+    /// UtilityVMPath is an optional path to the image used for the Utility VM.
+    pub fn utility_vm_path(&self) -> Option<&str> {
+        self.utility_vm_path.as_deref()
+    }
+
+    /// Mutable access to [`utility_vm_path`](Self::utility_vm_path).
+    pub fn utility_vm_path_mut(&mut self) -> &mut Option<String> {
+        &mut self.utility_vm_path
+    }
+}
+
+/// VM contains information for virtual-machine-based containers.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct VM {
+    /// Hypervisor specifies hypervisor-related configuration for virtual-machine-based containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hypervisor: Option<VMHypervisor>,
+    /// Kernel specifies kernel-related configuration for virtual-machine-based containers.
+    kernel: VMKernel,
+    /// Image specifies guest image related configuration for virtual-machine-based containers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image: Option<VMImage>,
+}
+
+impl VM {
+    /// Hypervisor specifies hypervisor-related configuration for virtual-machine-based containers.
+    pub fn hypervisor(&self) -> Option<&VMHypervisor> {
+        self.hypervisor.as_ref()
+    }
+
+    /// Kernel specifies kernel-related configuration for virtual-machine-based containers.
+    pub fn kernel(&self) -> &VMKernel {
+        &self.kernel
+    }
+
+    /// Image specifies guest image related configuration for virtual-machine-based containers.
+    pub fn image(&self) -> Option<&VMImage> {
+        self.image.as_ref()
+    }
+
+    /// Mutable access to [`hypervisor`](Self::hypervisor).
+    pub fn hypervisor_mut(&mut self) -> &mut Option<VMHypervisor> {
+        &mut self.hypervisor
+    }
+
+    /// Mutable access to [`kernel`](Self::kernel).
+    pub fn kernel_mut(&mut self) -> &mut VMKernel {
+        &mut self.kernel
+    }
+
+    /// Mutable access to [`image`](Self::image).
+    pub fn image_mut(&mut self) -> &mut Option<VMImage> {
+        &mut self.image
+    }
+}
+
+/// VMHypervisor contains information about the hypervisor to use for a virtual machine.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct VMHypervisor {
+    /// Path is the host path to the hypervisor used to manage the virtual machine.
+    path: String,
+    /// Parameters specifies parameters to pass to the hypervisor.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    parameters: Vec<String>,
+}
+
+impl VMHypervisor {
+    /// Path is the host path to the hypervisor used to manage the virtual machine.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Parameters specifies parameters to pass to the hypervisor.
+    pub fn parameters(&self) -> &[String] {
+        &self.parameters
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`parameters`](Self::parameters).
+    pub fn parameters_mut(&mut self) -> &mut Vec<String> {
+        &mut self.parameters
+    }
+}
+
+/// VMKernel contains information about the kernel to use for a virtual machine.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct VMKernel {
+    /// Path is the host path to the kernel used to boot the virtual machine.
+    path: String,
+    /// Parameters specifies parameters to pass to the kernel.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    parameters: Vec<String>,
+    /// InitRD is the host path to an initial ramdisk to be used by the kernel.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "initrd")]
+    init_rd: Option<String>,
+}
+
+impl VMKernel {
+    /// Path is the host path to the kernel used to boot the virtual machine.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Parameters specifies parameters to pass to the kernel.
+    pub fn parameters(&self) -> &[String] {
+        &self.parameters
+    }
+
+    /// InitRD is the host path to an initial ramdisk to be used by the kernel.
+    pub fn init_rd(&self) -> Option<&str> {
+        self.init_rd.as_deref()
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`parameters`](Self::parameters).
+    pub fn parameters_mut(&mut self) -> &mut Vec<String> {
+        &mut self.parameters
+    }
+
+    /// Mutable access to [`init_rd`](Self::init_rd).
+    pub fn init_rd_mut(&mut self) -> &mut Option<String> {
+        &mut self.init_rd
+    }
+}
+
+/// VMImage contains information about the virtual machine root image.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct VMImage {
+    /// Path is the host path to the root image that the VM kernel would boot into.
+    path: String,
+    /// Format is the root image format type (e.g. "qcow2", "raw", "vhd", etc).
+    format: String,
+}
+
+impl VMImage {
+    /// Path is the host path to the root image that the VM kernel would boot into.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Format is the root image format type (e.g. "qcow2", "raw", "vhd", etc).
+    pub fn format(&self) -> &str {
+        &self.format
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`format`](Self::format).
+    pub fn format_mut(&mut self) -> &mut String {
+        &mut self.format
+    }
+}
+
+/// Solaris contains platform-specific configuration for Solaris application containers.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct Solaris {
+    /// SMF FMRI which should go "online" before we start the container process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    milestone: Option<String>,
+    /// Maximum set of privileges any process in this container can obtain.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "limitpriv")]
+    limit_priv: Option<String>,
+    /// The maximum amount of shared memory allowed for this container.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "maxShmMemory")]
+    max_shm_memory: Option<String>,
+    /// Specification for automatic creation of network resources for this container.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    anet: Vec<SolarisAnet>,
+    /// Set limit on the amount of CPU time that can be used by container.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "cappedCPU")]
+    capped_cpu: Option<SolarisCappedCPU>,
+    /// The physical and swap caps on the memory that can be used by this container.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "cappedMemory")]
+    capped_memory: Option<SolarisCappedMemory>,
+}
+
+impl Solaris {
+    /// SMF FMRI which should go "online" before we start the container process.
+    pub fn milestone(&self) -> Option<&str> {
+        self.milestone.as_deref()
+    }
+
+    /// Maximum set of privileges any process in this container can obtain.
+    pub fn limit_priv(&self) -> Option<&str> {
+        self.limit_priv.as_deref()
+    }
+
+    /// The maximum amount of shared memory allowed for this container.
+    pub fn max_shm_memory(&self) -> Option<&str> {
+        self.max_shm_memory.as_deref()
+    }
+
+    /// Specification for automatic creation of network resources for this container.
+    pub fn anet(&self) -> &[SolarisAnet] {
+        &self.anet
+    }
+
+    /// Set limit on the amount of CPU time that can be used by container.
+    pub fn capped_cpu(&self) -> Option<&SolarisCappedCPU> {
+        self.capped_cpu.as_ref()
+    }
+
+    /// The physical and swap caps on the memory that can be used by this container.
+    pub fn capped_memory(&self) -> Option<&SolarisCappedMemory> {
+        self.capped_memory.as_ref()
+    }
+
+    /// Mutable access to [`milestone`](Self::milestone).
+    pub fn milestone_mut(&mut self) -> &mut Option<String> {
+        &mut self.milestone
+    }
+
+    /// Mutable access to [`limit_priv`](Self::limit_priv).
+    pub fn limit_priv_mut(&mut self) -> &mut Option<String> {
+        &mut self.limit_priv
+    }
+
+    /// Mutable access to [`max_shm_memory`](Self::max_shm_memory).
+    pub fn max_shm_memory_mut(&mut self) -> &mut Option<String> {
+        &mut self.max_shm_memory
+    }
+
+    /// Mutable access to [`anet`](Self::anet).
+    pub fn anet_mut(&mut self) -> &mut Vec<SolarisAnet> {
+        &mut self.anet
+    }
+
+    /// Mutable access to [`capped_cpu`](Self::capped_cpu).
+    pub fn capped_cpu_mut(&mut self) -> &mut Option<SolarisCappedCPU> {
+        &mut self.capped_cpu
+    }
+
+    /// Mutable access to [`capped_memory`](Self::capped_memory).
+    pub fn capped_memory_mut(&mut self) -> &mut Option<SolarisCappedMemory> {
+        &mut self.capped_memory
+    }
+}
+
+/// SolarisCappedCPU allows users to set limit on the amount of CPU time that can be used by container.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct SolarisCappedCPU {
+    /// Ncpus is the number of CPUs, e.g. "2.5" for two and a half CPUs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ncpus: Option<String>,
+}
+
+impl SolarisCappedCPU {
+    /// Ncpus is the number of CPUs, e.g. "2.5" for two and a half CPUs.
+    pub fn ncpus(&self) -> Option<&str> {
+        self.ncpus.as_deref()
+    }
+
+    /// Mutable access to [`ncpus`](Self::ncpus).
+    pub fn ncpus_mut(&mut self) -> &mut Option<String> {
+        &mut self.ncpus
+    }
+}
+
+/// SolarisCappedMemory allows users to set the physical and swap caps on the memory that can be used by this container.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct SolarisCappedMemory {
+    /// Physical memory cap, e.g. "512m".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    physical: Option<String>,
+    /// Swap cap, e.g. "512m".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    swap: Option<String>,
+}
+
+impl SolarisCappedMemory {
+    /// Physical memory cap, e.g. "512m".
+    pub fn physical(&self) -> Option<&str> {
+        self.physical.as_deref()
+    }
+
+    /// Swap cap, e.g. "512m".
+    pub fn swap(&self) -> Option<&str> {
+        self.swap.as_deref()
+    }
+
+    /// Mutable access to [`physical`](Self::physical).
+    pub fn physical_mut(&mut self) -> &mut Option<String> {
+        &mut self.physical
+    }
+
+    /// Mutable access to [`swap`](Self::swap).
+    pub fn swap_mut(&mut self) -> &mut Option<String> {
+        &mut self.swap
+    }
+}
+
+/// SolarisAnet provides the specification for automatic creation of network resources for this container.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct SolarisAnet {
+    /// Specify a name for the automatically created VNIC datalink.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    linkname: Option<String>,
+    /// Specify the link over which the VNIC will be created.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "lowerLink")]
+    lower_link: Option<String>,
+    /// The set of IP addresses that the container can use.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "allowedAddress")]
+    allowed_address: Option<String>,
+    /// Specifies whether allowedAddress limitation is to be applied to the VNIC.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "configureAllowedAddress"
+    )]
+    configure_allowed_address: Option<String>,
+    /// The value of the optional default router.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    defrouter: Option<String>,
+    /// Enable one or more types of link protection.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linkProtection")]
+    link_protection: Option<String>,
+    /// Set the VNIC's macAddress
+    #[serde(skip_serializing_if = "Option::is_none", rename = "macAddress")]
+    mac_address: Option<String>,
+}
+
+impl SolarisAnet {
+    /// Specify a name for the automatically created VNIC datalink.
+    pub fn linkname(&self) -> Option<&str> {
+        self.linkname.as_deref()
+    }
+
+    /// Specify the link over which the VNIC will be created.
+    pub fn lower_link(&self) -> Option<&str> {
+        self.lower_link.as_deref()
+    }
+
+    /// The set of IP addresses that the container can use.
+    pub fn allowed_address(&self) -> Option<&str> {
+        self.allowed_address.as_deref()
+    }
+
+    /// Specifies whether allowedAddress limitation is to be applied to the VNIC.
+    pub fn configure_allowed_address(&self) -> Option<&str> {
+        self.configure_allowed_address.as_deref()
+    }
+
+    /// The value of the optional default router.
+    pub fn defrouter(&self) -> Option<&str> {
+        self.defrouter.as_deref()
+    }
+
+    /// Enable one or more types of link protection.
+    pub fn link_protection(&self) -> Option<&str> {
+        self.link_protection.as_deref()
+    }
+
+    /// Set the VNIC's macAddress
+    pub fn mac_address(&self) -> Option<&str> {
+        self.mac_address.as_deref()
+    }
+
+    /// Mutable access to [`linkname`](Self::linkname).
+    pub fn linkname_mut(&mut self) -> &mut Option<String> {
+        &mut self.linkname
+    }
+
+    /// Mutable access to [`lower_link`](Self::lower_link).
+    pub fn lower_link_mut(&mut self) -> &mut Option<String> {
+        &mut self.lower_link
+    }
+
+    /// Mutable access to [`allowed_address`](Self::allowed_address).
+    pub fn allowed_address_mut(&mut self) -> &mut Option<String> {
+        &mut self.allowed_address
+    }
+
+    /// Mutable access to [`configure_allowed_address`](Self::configure_allowed_address).
+    pub fn configure_allowed_address_mut(&mut self) -> &mut Option<String> {
+        &mut self.configure_allowed_address
+    }
+
+    /// Mutable access to [`defrouter`](Self::defrouter).
+    pub fn defrouter_mut(&mut self) -> &mut Option<String> {
+        &mut self.defrouter
+    }
+
+    /// Mutable access to [`link_protection`](Self::link_protection).
+    pub fn link_protection_mut(&mut self) -> &mut Option<String> {
+        &mut self.link_protection
+    }
+
+    /// Mutable access to [`mac_address`](Self::mac_address).
+    pub fn mac_address_mut(&mut self) -> &mut Option<String> {
+        &mut self.mac_address
+    }
+}
+
+/// ZOS contains platform-specific configuration for z/OS based containers.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct ZOS {
+    /// Namespaces contains the namespaces that are created and/or joined by the container
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    namespaces: Vec<ZOSNamespace>,
+    /// Devices are a list of device nodes that are created for the container
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    devices: Vec<ZOSDevice>,
+}
+
+impl ZOS {
+    /// Namespaces contains the namespaces that are created and/or joined by the container
+    pub fn namespaces(&self) -> &[ZOSNamespace] {
+        &self.namespaces
+    }
+
+    /// Devices are a list of device nodes that are created for the container
+    pub fn devices(&self) -> &[ZOSDevice] {
+        &self.devices
+    }
+
+    /// Mutable access to [`namespaces`](Self::namespaces).
+    pub fn namespaces_mut(&mut self) -> &mut Vec<ZOSNamespace> {
+        &mut self.namespaces
+    }
+
+    /// Mutable access to [`devices`](Self::devices).
+    pub fn devices_mut(&mut self) -> &mut Vec<ZOSDevice> {
+        &mut self.devices
+    }
+}
+
+/// ZOSNamespace is the configuration for a z/OS namespace
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct ZOSNamespace {
+    /// Type is the type of namespace
+    #[serde(rename = "type")]
+    namespace_type: ZOSNamespaceType,
+    /// Path is a path to an existing namespace persisted on disk that can be joined
+    /// and is of the same type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+impl ZOSNamespace {
+    /// Type is the type of namespace
+    pub fn namespace_type(&self) -> ZOSNamespaceType {
+        self.namespace_type
+    }
+
+    /// Path is a path to an existing namespace persisted on disk that can be joined
+    /// and is of the same type
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Mutable access to [`namespace_type`](Self::namespace_type).
+    pub fn namespace_type_mut(&mut self) -> &mut ZOSNamespaceType {
+        &mut self.namespace_type
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut Option<String> {
+        &mut self.path
+    }
+}
+
+string_enum! {
+    /// ZOSNamespaceType is one of the z/OS namespaces
+    #[derive(Default)]
+    pub enum ZOSNamespaceType {
+        /// PID namespace for isolation of process PIDs
+        #[default]
+        Pid = "pid",
+        /// Mount namespace for isolation of mount points
+        Mount = "mount",
+        /// IPC namespace for isolation of System V IPC, POSIX message queues
+        Ipc = "ipc",
+        /// UTS namespace for isolation of hostname and NIS domain name
+        Uts = "uts",
+    }
+}
+
+/// ZOSDevice represents the mknod information for a z/OS special device file
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct ZOSDevice {
+    /// Path to the device.
+    path: String,
+    /// Device type, block, char, etc.
+    #[serde(rename = "type")]
+    device_type: String,
+    /// Major is the device's major number.
+    major: i64,
+    /// Minor is the device's minor number.
+    minor: i64,
+    /// FileMode permission bits for the device.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fileMode")]
+    file_mode: Option<u32>,
+    /// UID of the device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<u32>,
+    /// Gid of the device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gid: Option<u32>,
+}
+
+impl ZOSDevice {
+    /// Path to the device.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Device type, block, char, etc.
+    pub fn device_type(&self) -> &str {
+        &self.device_type
+    }
+
+    /// Major is the device's major number.
+    pub fn major(&self) -> i64 {
+        self.major
+    }
+
+    /// Minor is the device's minor number.
+    pub fn minor(&self) -> i64 {
+        self.minor
+    }
+
+    /// FileMode permission bits for the device.
+    pub fn file_mode(&self) -> Option<u32> {
+        self.file_mode
+    }
+
+    /// UID of the device.
+    pub fn uid(&self) -> Option<u32> {
+        self.uid
+    }
+
+    /// Gid of the device.
+    pub fn gid(&self) -> Option<u32> {
+        self.gid
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`device_type`](Self::device_type).
+    pub fn device_type_mut(&mut self) -> &mut String {
+        &mut self.device_type
+    }
+
+    /// Mutable access to [`major`](Self::major).
+    pub fn major_mut(&mut self) -> &mut i64 {
+        &mut self.major
+    }
+
+    /// Mutable access to [`minor`](Self::minor).
+    pub fn minor_mut(&mut self) -> &mut i64 {
+        &mut self.minor
+    }
+
+    /// Mutable access to [`file_mode`](Self::file_mode).
+    pub fn file_mode_mut(&mut self) -> &mut Option<u32> {
+        &mut self.file_mode
+    }
+
+    /// Mutable access to [`uid`](Self::uid).
+    pub fn uid_mut(&mut self) -> &mut Option<u32> {
+        &mut self.uid
+    }
+
+    /// Mutable access to [`gid`](Self::gid).
+    pub fn gid_mut(&mut self) -> &mut Option<u32> {
+        &mut self.gid
+    }
+}
+
+/// FreeBSD specifies FreeBSD-specific configuration options
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct FreeBSD {
+    /// Devices which are accessible in the container
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    devices: Vec<FreeBSDDevice>,
+    /// Jail definition for this container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jail: Option<FreeBSDJail>,
+}
+
+impl FreeBSD {
+    /// Devices which are accessible in the container
+    pub fn devices(&self) -> &[FreeBSDDevice] {
+        &self.devices
+    }
+
+    /// Jail definition for this container
+    pub fn jail(&self) -> Option<&FreeBSDJail> {
+        self.jail.as_ref()
+    }
+
+    /// Mutable access to [`devices`](Self::devices).
+    pub fn devices_mut(&mut self) -> &mut Vec<FreeBSDDevice> {
+        &mut self.devices
+    }
+
+    /// Mutable access to [`jail`](Self::jail).
+    pub fn jail_mut(&mut self) -> &mut Option<FreeBSDJail> {
+        &mut self.jail
+    }
+}
+
+/// FreeBSDDevice describes a devfs rule exposing a device to the container
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct FreeBSDDevice {
+    /// Path to the device, relative to /dev.
+    path: String,
+    /// FileMode permission bits for the device.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<u32>,
+}
+
+impl FreeBSDDevice {
+    /// Path to the device, relative to /dev.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// FileMode permission bits for the device.
+    pub fn mode(&self) -> Option<u32> {
+        self.mode
+    }
+
+    /// Mutable access to [`path`](Self::path).
+    pub fn path_mut(&mut self) -> &mut String {
+        &mut self.path
+    }
+
+    /// Mutable access to [`mode`](Self::mode).
+    pub fn mode_mut(&mut self) -> &mut Option<u32> {
+        &mut self.mode
+    }
+}
+
+string_enum! {
+    /// FreeBSDSharing controls access to a jail feature: disabled, shared with the
+    /// parent, or given new private state in the container.
+    pub enum FreeBSDSharing {
+        /// The feature is not available in the container.
+        Disable = "disable",
+        /// The container gets its own private state.
+        New = "new",
+        /// The container shares the state of its parent.
+        Inherit = "inherit",
+    }
+}
+
+/// FreeBSDJail describes how to configure the container's jail
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct FreeBSDJail {
+    /// Parent jail name - this can be used to share a single vnet
+    /// across several containers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+    /// Whether to use parent UTS names or override in the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    host: Option<FreeBSDSharing>,
+    /// IPv4 address sharing for the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ip4: Option<FreeBSDSharing>,
+    /// IPv4 addresses for the container
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "ip4Addr", default)]
+    ip4_addr: Vec<String>,
+    /// IPv6 address sharing for the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ip6: Option<FreeBSDSharing>,
+    /// IPv6 addresses for the container
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "ip6Addr", default)]
+    ip6_addr: Vec<String>,
+    /// Which network stack to use for the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vnet: Option<FreeBSDSharing>,
+    /// If set, Ip4Addr and Ip6Addr addresses will be added to this interface
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interface: Option<String>,
+    /// List interfaces to be moved to the container's vnet
+    #[serde(skip_serializing_if = "Vec::is_empty", rename = "vnetInterfaces", default)]
+    vnet_interfaces: Vec<String>,
+    /// SystemV IPC message sharing for the container
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sysvmsg")]
+    sysv_msg: Option<FreeBSDSharing>,
+    /// SystemV semaphore message sharing for the container
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sysvsem")]
+    sysv_sem: Option<FreeBSDSharing>,
+    /// SystemV memory sharing for the container
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sysvshm")]
+    sysv_shm: Option<FreeBSDSharing>,
+    /// Mount visibility (see jail(8) for details)
+    #[serde(skip_serializing_if = "Option::is_none", rename = "enforceStatfs")]
+    enforce_statfs: Option<i32>,
+    /// Jail capabilities
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow: Option<FreeBSDJailAllow>,
+}
+
+impl FreeBSDJail {
+    /// Parent jail name - this can be used to share a single vnet
+    /// across several containers
+    pub fn parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+
+    /// Whether to use parent UTS names or override in the container
+    pub fn host(&self) -> Option<FreeBSDSharing> {
+        self.host
+    }
+
+    /// IPv4 address sharing for the container
+    pub fn ip4(&self) -> Option<FreeBSDSharing> {
+        self.ip4
+    }
+
+    /// IPv4 addresses for the container
+    pub fn ip4_addr(&self) -> &[String] {
+        &self.ip4_addr
+    }
+
+    /// IPv6 address sharing for the container
+    pub fn ip6(&self) -> Option<FreeBSDSharing> {
+        self.ip6
+    }
+
+    /// IPv6 addresses for the container
+    pub fn ip6_addr(&self) -> &[String] {
+        &self.ip6_addr
+    }
+
+    /// Which network stack to use for the container
+    pub fn vnet(&self) -> Option<FreeBSDSharing> {
+        self.vnet
+    }
+
+    /// If set, Ip4Addr and Ip6Addr addresses will be added to this interface
+    pub fn interface(&self) -> Option<&str> {
+        self.interface.as_deref()
+    }
+
+    /// List interfaces to be moved to the container's vnet
+    pub fn vnet_interfaces(&self) -> &[String] {
+        &self.vnet_interfaces
+    }
+
+    /// SystemV IPC message sharing for the container
+    pub fn sysv_msg(&self) -> Option<FreeBSDSharing> {
+        self.sysv_msg
+    }
+
+    /// SystemV semaphore message sharing for the container
+    pub fn sysv_sem(&self) -> Option<FreeBSDSharing> {
+        self.sysv_sem
+    }
+
+    /// SystemV memory sharing for the container
+    pub fn sysv_shm(&self) -> Option<FreeBSDSharing> {
+        self.sysv_shm
+    }
+
+    /// Mount visibility (see jail(8) for details)
+    pub fn enforce_statfs(&self) -> Option<i32> {
+        self.enforce_statfs
+    }
+
+    /// Jail capabilities
+    pub fn allow(&self) -> Option<&FreeBSDJailAllow> {
+        self.allow.as_ref()
+    }
+
+    /// Mutable access to [`parent`](Self::parent).
+    pub fn parent_mut(&mut self) -> &mut Option<String> {
+        &mut self.parent
+    }
+
+    /// Mutable access to [`host`](Self::host).
+    pub fn host_mut(&mut self) -> &mut Option<FreeBSDSharing> {
+        &mut self.host
+    }
+
+    /// Mutable access to [`ip4`](Self::ip4).
+    pub fn ip4_mut(&mut self) -> &mut Option<FreeBSDSharing> {
+        &mut self.ip4
+    }
+
+    /// Mutable access to [`ip4_addr`](Self::ip4_addr).
+    pub fn ip4_addr_mut(&mut self) -> &mut Vec<String> {
+        &mut self.ip4_addr
+    }
+
+    /// Mutable access to [`ip6`](Self::ip6).
+    pub fn ip6_mut(&mut self) -> &mut Option<FreeBSDSharing> {
+        &mut self.ip6
+    }
+
+    /// Mutable access to [`ip6_addr`](Self::ip6_addr).
+    pub fn ip6_addr_mut(&mut self) -> &mut Vec<String> {
+        &mut self.ip6_addr
+    }
+
+    /// Mutable access to [`vnet`](Self::vnet).
+    pub fn vnet_mut(&mut self) -> &mut Option<FreeBSDSharing> {
+        &mut self.vnet
+    }
+
+    /// Mutable access to [`interface`](Self::interface).
+    pub fn interface_mut(&mut self) -> &mut Option<String> {
+        &mut self.interface
+    }
+
+    /// Mutable access to [`vnet_interfaces`](Self::vnet_interfaces).
+    pub fn vnet_interfaces_mut(&mut self) -> &mut Vec<String> {
+        &mut self.vnet_interfaces
+    }
+
+    /// Mutable access to [`sysv_msg`](Self::sysv_msg).
+    pub fn sysv_msg_mut(&mut self) -> &mut Option<FreeBSDSharing> {
+        &mut self.sysv_msg
+    }
+
+    /// Mutable access to [`sysv_sem`](Self::sysv_sem).
+    pub fn sysv_sem_mut(&mut self) -> &mut Option<FreeBSDSharing> {
+        &mut self.sysv_sem
+    }
+
+    /// Mutable access to [`sysv_shm`](Self::sysv_shm).
+    pub fn sysv_shm_mut(&mut self) -> &mut Option<FreeBSDSharing> {
+        &mut self.sysv_shm
+    }
+
+    /// Mutable access to [`enforce_statfs`](Self::enforce_statfs).
+    pub fn enforce_statfs_mut(&mut self) -> &mut Option<i32> {
+        &mut self.enforce_statfs
+    }
+
+    /// Mutable access to [`allow`](Self::allow).
+    pub fn allow_mut(&mut self) -> &mut Option<FreeBSDJailAllow> {
+        &mut self.allow
+    }
+}
+
+/// FreeBSDJailAllow describes jail capabilities
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct FreeBSDJailAllow {
+    /// Allow the container to change its hostname (allow.set_hostname)
+    #[serde(skip_serializing_if = "Option::is_none", rename = "setHostname")]
+    set_hostname: Option<bool>,
+    /// Allow raw sockets (allow.raw_sockets)
+    #[serde(skip_serializing_if = "Option::is_none", rename = "rawSockets")]
+    raw_sockets: Option<bool>,
+    /// Allow changing system file flags (allow.chflags)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chflags: Option<bool>,
+    /// File system types which may be mounted, one allow.mount.<type> parameter each
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    mount: Vec<String>,
+    /// Allow manipulating quotas (allow.quotas)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quotas: Option<bool>,
+    /// Allow sockets other than IPv4, IPv6 and unix domain (allow.socket_af)
+    #[serde(skip_serializing_if = "Option::is_none", rename = "socketAf")]
+    socket_af: Option<bool>,
+    /// Allow locking memory (allow.mlock)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mlock: Option<bool>,
+    /// Allow binding to reserved ports (allow.reserved_ports)
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reservedPorts")]
+    reserved_ports: Option<bool>,
+    /// Allow root in the jail to use privileges (allow.suser)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suser: Option<bool>,
+}
+
+impl FreeBSDJailAllow {
+    /// Allow the container to change its hostname (allow.set_hostname)
+    pub fn set_hostname(&self) -> Option<bool> {
+        self.set_hostname
+    }
+
+    /// Allow raw sockets (allow.raw_sockets)
+    pub fn raw_sockets(&self) -> Option<bool> {
+        self.raw_sockets
+    }
+
+    /// Allow changing system file flags (allow.chflags)
+    pub fn chflags(&self) -> Option<bool> {
+        self.chflags
+    }
+
+    /// File system types which may be mounted, one allow.mount.<type> parameter each
+    pub fn mount(&self) -> &[String] {
+        &self.mount
+    }
+
+    /// Allow manipulating quotas (allow.quotas)
+    pub fn quotas(&self) -> Option<bool> {
+        self.quotas
+    }
+
+    /// Allow sockets other than IPv4, IPv6 and unix domain (allow.socket_af)
+    pub fn socket_af(&self) -> Option<bool> {
+        self.socket_af
+    }
+
+    /// Allow locking memory (allow.mlock)
+    pub fn mlock(&self) -> Option<bool> {
+        self.mlock
+    }
+
+    /// Allow binding to reserved ports (allow.reserved_ports)
+    pub fn reserved_ports(&self) -> Option<bool> {
+        self.reserved_ports
+    }
+
+    /// Allow root in the jail to use privileges (allow.suser)
+    pub fn suser(&self) -> Option<bool> {
+        self.suser
+    }
+
+    /// Mutable access to [`set_hostname`](Self::set_hostname).
+    pub fn set_hostname_mut(&mut self) -> &mut Option<bool> {
+        &mut self.set_hostname
+    }
+
+    /// Mutable access to [`raw_sockets`](Self::raw_sockets).
+    pub fn raw_sockets_mut(&mut self) -> &mut Option<bool> {
+        &mut self.raw_sockets
+    }
+
+    /// Mutable access to [`chflags`](Self::chflags).
+    pub fn chflags_mut(&mut self) -> &mut Option<bool> {
+        &mut self.chflags
+    }
+
+    /// Mutable access to [`mount`](Self::mount).
+    pub fn mount_mut(&mut self) -> &mut Vec<String> {
+        &mut self.mount
+    }
+
+    /// Mutable access to [`quotas`](Self::quotas).
+    pub fn quotas_mut(&mut self) -> &mut Option<bool> {
+        &mut self.quotas
+    }
+
+    /// Mutable access to [`socket_af`](Self::socket_af).
+    pub fn socket_af_mut(&mut self) -> &mut Option<bool> {
+        &mut self.socket_af
+    }
+
+    /// Mutable access to [`mlock`](Self::mlock).
+    pub fn mlock_mut(&mut self) -> &mut Option<bool> {
+        &mut self.mlock
+    }
+
+    /// Mutable access to [`reserved_ports`](Self::reserved_ports).
+    pub fn reserved_ports_mut(&mut self) -> &mut Option<bool> {
+        &mut self.reserved_ports
+    }
+
+    /// Mutable access to [`suser`](Self::suser).
+    pub fn suser_mut(&mut self) -> &mut Option<bool> {
+        &mut self.suser
+    }
+}
+
+impl_to_builder! {
+    Spec => SpecBuilder {
+        version, process, root, hostname, domainname, mounts, hooks, annotations, linux,
+        solaris, windows, vm, zos, freebsd,
+    },
+    Process => ProcessBuilder {
+        terminal, console_size, user, args, command_line, env, cwd, capabilities, rlimits,
+        no_new_privileges, app_armor_profile, oom_score_adj, scheduler, selinux_label,
+        io_priority, exec_cpu_affinity,
+    },
+    LinuxCapabilities => LinuxCapabilitiesBuilder {
+        bounding, effective, inheritable, permitted, ambient,
+    },
+    ConsoleSizeBox => ConsoleSizeBoxBuilder { height, width },
+    User => UserBuilder { uid, gid, umask, additional_gids, username },
+    Root => RootBuilder { path, readonly },
+    Mount => MountBuilder { destination, mount_type, source, options, uid_mappings, gid_mappings },
+    Hook => HookBuilder { path, args, env, timeout },
+    Hooks => HooksBuilder {
+        prestart, create_runtime, create_container, start_container, poststart, poststop,
+    },
+    Linux => LinuxBuilder {
+        uid_mappings, gid_mappings, sysctl, resources, cgroups_path, namespaces, devices,
+        seccomp, rootfs_propagation, masked_paths, readonly_paths, mount_label, intel_rdt,
+        personality, time_offsets, net_devices,
+    },
+    LinuxNamespace => LinuxNamespaceBuilder { namespace_type, path },
+    LinuxIDMapping => LinuxIDMappingBuilder { container_id, host_id, size },
+    POSIXRlimit => POSIXRlimitBuilder { rlimit_type, hard, soft },
+    Scheduler => SchedulerBuilder { policy, nice, priority, flags, runtime, deadline, period },
+    LinuxIOPriority => LinuxIOPriorityBuilder { class, priority },
+    CPUAffinity => CPUAffinityBuilder { initial, final_cpus },
+    LinuxHugepageLimit => LinuxHugepageLimitBuilder { page_size, limit },
+    LinuxInterfacePriority => LinuxInterfacePriorityBuilder { name, priority },
+    LinuxWeightDevice => LinuxWeightDeviceBuilder { major, minor, weight, leaf_weight },
+    LinuxThrottleDevice => LinuxThrottleDeviceBuilder { major, minor, rate },
+    LinuxBlockIO => LinuxBlockIOBuilder {
+        weight, leaf_weight, weight_device, throttle_read_bps_device,
+        throttle_write_bps_device, throttle_read_iops_device, throttle_write_iops_device,
+    },
+    LinuxMemory => LinuxMemoryBuilder {
+        limit, reservation, swap, kernel, kernel_tcp, swappiness, disable_oom_killer,
+        use_hierarchy, check_before_update,
+    },
+    LinuxCPU => LinuxCPUBuilder {
+        shares, quota, period, realtime_runtime, realtime_period, cpus, mems, burst, idle,
+    },
+    LinuxPids => LinuxPidsBuilder { limit },
+    LinuxNetwork => LinuxNetworkBuilder { class_id, priorities },
+    LinuxRdma => LinuxRdmaBuilder { hca_handles, hca_objects },
+    LinuxResources => LinuxResourcesBuilder {
+        devices, memory, cpu, pids, block_io, hugepage_limits, network, rdma, unified,
+    },
+    LinuxDevice => LinuxDeviceBuilder { path, device_type, major, minor, file_mode, uid, gid },
+    LinuxDeviceCgroup => LinuxDeviceCgroupBuilder { allow, device_type, major, minor, access },
+    LinuxNetDevice => LinuxNetDeviceBuilder { name },
+    LinuxTimeOffset => LinuxTimeOffsetBuilder { secs, nanosecs },
+    LinuxPersonality => LinuxPersonalityBuilder { domain, flags },
+    LinuxSeccomp => LinuxSeccompBuilder {
+        default_action, default_errno_ret, architectures, flags, listener_path,
+        listener_metadata, syscalls,
+    },
+    LinuxSeccompArg => LinuxSeccompArgBuilder { index, value, value_two, op },
+    LinuxSyscall => LinuxSyscallBuilder { names, action, errno_ret, args },
+    LinuxIntelRdt => LinuxIntelRdtBuilder {
+        clos_id, l3_cache_schema, mem_bw_schema, schemata, enable_cmt, enable_mbm,
+    },
+    Windows => WindowsBuilder {
+        layer_folders, devices, resources, credential_spec, servicing,
+        ignore_flushes_during_boot, hyperv, network,
+    },
+    WindowsDevice => WindowsDeviceBuilder { id, id_type },
+    WindowsResources => WindowsResourcesBuilder { memory, cpu, storage },
+    WindowsMemoryResources => WindowsMemoryResourcesBuilder { limit },
+    WindowsCPUResources => WindowsCPUResourcesBuilder { count, shares, maximum },
+    WindowsStorageResources => WindowsStorageResourcesBuilder { iops, bps, sandbox_size },
+    WindowsNetwork => WindowsNetworkBuilder {
+        endpoint_list, allow_unqualified_dns_query, dns_search_list,
+        network_shared_container_name, network_namespace,
+    },
+    WindowsHyperV => WindowsHyperVBuilder { utility_vm_path },
+    VM => VMBuilder { hypervisor, kernel, image },
+    VMHypervisor => VMHypervisorBuilder { path, parameters },
+    VMKernel => VMKernelBuilder { path, parameters, init_rd },
+    VMImage => VMImageBuilder { path, format },
+    Solaris => SolarisBuilder {
+        milestone, limit_priv, max_shm_memory, anet, capped_cpu, capped_memory,
+    },
+    SolarisCappedCPU => SolarisCappedCPUBuilder { ncpus },
+    SolarisCappedMemory => SolarisCappedMemoryBuilder { physical, swap },
+    SolarisAnet => SolarisAnetBuilder {
+        linkname, lower_link, allowed_address, configure_allowed_address, defrouter,
+        link_protection, mac_address,
+    },
+    ZOS => ZOSBuilder { namespaces, devices },
+    ZOSNamespace => ZOSNamespaceBuilder { namespace_type, path },
+    ZOSDevice => ZOSDeviceBuilder { path, device_type, major, minor, file_mode, uid, gid },
+    FreeBSD => FreeBSDBuilder { devices, jail },
+    FreeBSDDevice => FreeBSDDeviceBuilder { path, mode },
+    FreeBSDJail => FreeBSDJailBuilder {
+        parent, host, ip4, ip4_addr, ip6, ip6_addr, vnet, interface, vnet_interfaces,
+        sysv_msg, sysv_sem, sysv_shm, enforce_statfs, allow,
+    },
+    FreeBSDJailAllow => FreeBSDJailAllowBuilder {
+        set_hostname, raw_sockets, chflags, mount, quotas, socket_af, mlock, reserved_ports,
+        suser,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deserializes `hooks`, serializes the result and deserializes it again, asserting that
+    /// the second pass is stable. Returns the first-pass hooks.
+    fn round_trip_hooks(hooks: &str) -> Hooks {
+        let parsed: Hooks = serde_json::from_str(hooks).unwrap();
+        let json = serde_json::to_value(&parsed).unwrap();
+        let reparsed: Hooks = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&reparsed).unwrap(), json);
+        parsed
+    }
+
+    #[test]
+    fn poststart_object_form() {
+        let hooks = round_trip_hooks(
+            r#"{"poststart": [{"path": "/usr/bin/notify-start", "args": ["notify-start"], "timeout": 5}]}"#,
+        );
+        let hook = &hooks.poststart()[0];
+        assert_eq!(hook.path(), "/usr/bin/notify-start");
+        assert_eq!(hook.args(), ["notify-start"]);
+        assert_eq!(hook.timeout(), Some(5));
+    }
+
+    #[test]
+    fn poststart_legacy_string_form() {
+        let hooks = round_trip_hooks(r#"{"poststart": ["/usr/bin/notify-start"]}"#);
+        assert_eq!(hooks.poststart().len(), 1);
+        assert_eq!(hooks.poststart()[0].path(), "/usr/bin/notify-start");
+        assert!(hooks.poststart()[0].args().is_empty());
+        assert_eq!(
+            serde_json::to_value(&hooks).unwrap(),
+            serde_json::json!({"poststart": [{"path": "/usr/bin/notify-start"}]})
+        );
+    }
+
+    #[test]
+    fn poststart_mixed_form() {
+        let hooks = round_trip_hooks(
+            r#"{"poststart": ["/usr/bin/first", {"path": "/usr/bin/second", "env": ["A=1"]}]}"#,
+        );
+        let paths: Vec<&str> = hooks.poststart().iter().map(Hook::path).collect();
+        assert_eq!(paths, ["/usr/bin/first", "/usr/bin/second"]);
+        assert_eq!(hooks.poststart()[1].env(), ["A=1"]);
+    }
+
+    #[test]
+    fn poststart_malformed_hook_error() {
+        // The untagged fallback hides the underlying cause, such as the missing `path` here.
+        for hooks in &[
+            r#"{"poststart": [{"args": ["x"]}]}"#,
+            r#"{"poststart": [42]}"#,
+        ] {
+            let err = serde_json::from_str::<Hooks>(hooks).unwrap_err();
+            assert!(
+                err.to_string()
+                    .starts_with("data did not match any variant of untagged enum LegacyHook"),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn getters_expose_parsed_values() {
+        let spec: Spec = serde_json::from_str(
+            r#"{
+                "ociVersion": "1.2.1",
+                "process": {
+                    "terminal": true,
+                    "user": {"uid": 1000, "gid": 1000},
+                    "args": ["sh", "-c", "true"],
+                    "env": ["PATH=/usr/bin"],
+                    "cwd": "/home"
+                },
+                "root": {"path": "rootfs", "readonly": true},
+                "hostname": "box",
+                "mounts": [{"destination": "/tmp", "type": "tmpfs", "source": "tmpfs", "options": ["nosuid"]}],
+                "annotations": {"org.example.key": "value"},
+                "linux": {
+                    "namespaces": [{"type": "pid"}, {"type": "network", "path": "/var/run/netns/box"}],
+                    "resources": {"memory": {"limit": 1048576}}
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(spec.version(), "1.2.1");
+        assert_eq!(spec.hostname(), Some("box"));
+        assert_eq!(spec.annotations()["org.example.key"], "value");
+        assert!(spec.hooks().is_none());
+
+        let process = spec.process().unwrap();
+        assert_eq!(process.terminal(), Some(true));
+        assert_eq!(process.user().uid(), 1000);
+        assert_eq!(process.args(), ["sh", "-c", "true"]);
+        assert_eq!(process.env(), ["PATH=/usr/bin"]);
+        assert_eq!(process.cwd(), "/home");
+
+        let root = spec.root().unwrap();
+        assert_eq!(root.path(), "rootfs");
+        assert_eq!(root.readonly(), Some(true));
+
+        let mount = &spec.mounts()[0];
+        assert_eq!(mount.destination(), "/tmp");
+        assert_eq!(mount.mount_type(), Some("tmpfs"));
+        assert_eq!(mount.source(), Some("tmpfs"));
+        assert_eq!(mount.options(), ["nosuid"]);
+
+        let linux = spec.linux().unwrap();
+        assert_eq!(linux.namespaces()[0].path(), None);
+        assert_eq!(linux.namespaces()[1].path(), Some("/var/run/netns/box"));
+        let memory = linux.resources().unwrap().memory().unwrap();
+        assert_eq!(memory.limit(), Some(1048576));
+    }
+
+    fn process_with_env(env: &[&str]) -> Process {
+        ProcessBuilder::default()
+            .env(env.iter().map(|e| e.to_string()).collect::<Vec<_>>())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn set_env_replaces_an_existing_key() {
+        let mut process = process_with_env(&["PATH=/bin", "HOME=/root"]);
+        process.set_env("PATH", "/usr/bin:/bin");
+        assert_eq!(process.env(), ["PATH=/usr/bin:/bin", "HOME=/root"]);
+    }
+
+    #[test]
+    fn set_env_appends_a_new_key() {
+        let mut process = process_with_env(&["PATH=/bin"]);
+        process.set_env("TERM", "xterm");
+        assert_eq!(process.env(), ["PATH=/bin", "TERM=xterm"]);
+    }
+
+    #[test]
+    fn env_keys_match_exactly() {
+        let mut process = process_with_env(&["PATHX=/opt", "PA=1"]);
+        process.set_env("PATH", "/bin");
+        assert_eq!(process.env(), ["PATHX=/opt", "PA=1", "PATH=/bin"]);
+        assert!(!process.remove_env("PAT"));
+        assert_eq!(process.env().len(), 3);
+    }
+
+    #[test]
+    fn env_entries_without_equals_use_the_whole_entry_as_key() {
+        let mut process = process_with_env(&["DEBUG", "DEBUGGER=gdb"]);
+        process.set_env("DEBUG", "1");
+        assert_eq!(process.env(), ["DEBUG=1", "DEBUGGER=gdb"]);
+
+        let mut process = process_with_env(&["DEBUG", "DEBUGGER=gdb"]);
+        assert!(process.remove_env("DEBUG"));
+        assert_eq!(process.env(), ["DEBUGGER=gdb"]);
+    }
+
+    #[test]
+    fn remove_env_reports_whether_the_key_was_present() {
+        let mut process = process_with_env(&["A=1", "B=2", "A=3"]);
+        assert!(process.remove_env("A"));
+        assert_eq!(process.env(), ["B=2"]);
+        assert!(!process.remove_env("A"));
+        assert!(!process.remove_env("C"));
+        assert_eq!(process.env(), ["B=2"]);
+    }
+
+    #[test]
+    fn to_builder_round_trips() {
+        let mut spec = Spec::default_linux();
+        *spec.hostname_mut() = Some("box".to_string());
+        let process = spec.process().unwrap();
+        let rebuilt = process.to_builder().build().unwrap();
+        assert_eq!(
+            serde_json::to_value(&rebuilt).unwrap(),
+            serde_json::to_value(process).unwrap()
+        );
+
+        let rebuilt = spec.to_builder().build().unwrap();
+        assert_eq!(
+            serde_json::to_value(&rebuilt).unwrap(),
+            serde_json::to_value(&spec).unwrap()
+        );
+
+        let renamed = spec
+            .to_builder()
+            .hostname("other".to_string())
+            .build()
+            .unwrap();
+        assert_eq!(renamed.hostname(), Some("other"));
+        assert_eq!(
+            serde_json::to_value(renamed.linux()).unwrap(),
+            serde_json::to_value(spec.linux()).unwrap()
+        );
+    }
+
+    #[test]
+    fn namespace_type_names() {
+        let kinds = [
+            (LinuxNamespaceType::Pid, "pid"),
+            (LinuxNamespaceType::Network, "network"),
+            (LinuxNamespaceType::Mount, "mount"),
+            (LinuxNamespaceType::Ipc, "ipc"),
+            (LinuxNamespaceType::Uts, "uts"),
+            (LinuxNamespaceType::User, "user"),
+            (LinuxNamespaceType::Cgroup, "cgroup"),
+            (LinuxNamespaceType::Time, "time"),
+        ];
+        for &(kind, name) in kinds.iter() {
+            assert_eq!(serde_json::to_value(kind).unwrap(), name);
+            assert_eq!(
+                serde_json::from_value::<LinuxNamespaceType>(name.into()).unwrap(),
+                kind
+            );
+            assert_eq!(name.parse::<LinuxNamespaceType>().unwrap(), kind);
+            assert_eq!(kind.to_string(), name);
+        }
+        assert!(serde_json::from_str::<LinuxNamespaceType>(r#""net""#).is_err());
+        assert!("NETWORK".parse::<LinuxNamespaceType>().is_err());
+    }
+
+    #[test]
+    fn namespace_operations() {
+        let mut linux: Linux =
+            serde_json::from_str(r#"{"namespaces": [{"type": "pid"}]}"#).unwrap();
+        assert!(linux.has_namespace(LinuxNamespaceType::Pid));
+        assert!(!linux.has_namespace(LinuxNamespaceType::Network));
+
+        linux.add_namespace(LinuxNamespaceType::Network, None);
+        linux.add_namespace(
+            LinuxNamespaceType::Network,
+            Some("/var/run/netns/box".to_string()),
+        );
+        assert_eq!(linux.namespaces().len(), 2);
+        let network = linux.namespace(LinuxNamespaceType::Network).unwrap();
+        assert_eq!(network.path(), Some("/var/run/netns/box"));
+
+        let removed = linux.remove_namespace(LinuxNamespaceType::Pid).unwrap();
+        assert_eq!(removed.namespace_type(), LinuxNamespaceType::Pid);
+        assert!(!linux.has_namespace(LinuxNamespaceType::Pid));
+        assert!(linux.remove_namespace(LinuxNamespaceType::Pid).is_none());
+        assert_eq!(
+            serde_json::to_value(linux.namespaces()).unwrap(),
+            serde_json::json!([{"type": "network", "path": "/var/run/netns/box"}])
+        );
+    }
+
+    #[test]
+    fn seccomp_enums_use_the_spec_spelling() {
+        let seccomp: LinuxSeccomp = serde_json::from_str(
+            r#"{
+                "defaultAction": "SCMP_ACT_ERRNO",
+                "architectures": ["SCMP_ARCH_X86_64", "SCMP_ARCH_X32", "SCMP_ARCH_AARCH64"],
+                "flags": ["SECCOMP_FILTER_FLAG_LOG", "SECCOMP_FILTER_FLAG_SPEC_ALLOW"],
+                "syscalls": [{
+                    "names": ["personality"],
+                    "action": "SCMP_ACT_ALLOW",
+                    "args": [{"index": 0, "value": 8, "op": "SCMP_CMP_EQ"}]
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(seccomp.default_action(), LinuxSeccompAction::Errno);
+        assert_eq!(
+            seccomp.architectures(),
+            [Arch::X86_64, Arch::X32, Arch::Aarch64]
+        );
+        assert_eq!(
+            seccomp.flags(),
+            [LinuxSeccompFlag::Log, LinuxSeccompFlag::SpecAllow]
+        );
+        assert_eq!(seccomp.syscalls()[0].action(), LinuxSeccompAction::Allow);
+        assert_eq!(
+            seccomp.syscalls()[0].args()[0].op(),
+            LinuxSeccompOperator::Equal
+        );
+
+        assert_eq!(
+            "SCMP_ACT_KILL_PROCESS".parse(),
+            Ok(LinuxSeccompAction::KillProcess)
+        );
+        assert_eq!(LinuxSeccompAction::Notify.to_string(), "SCMP_ACT_NOTIFY");
+        assert_eq!("SCMP_ARCH_PPC64LE".parse(), Ok(Arch::Ppc64Le));
+        assert_eq!(Arch::S390X.as_str(), "SCMP_ARCH_S390X");
+        assert_eq!(
+            "SECCOMP_FILTER_FLAG_WAIT_KILLABLE_RECV".parse(),
+            Ok(LinuxSeccompFlag::WaitKillableRecv)
+        );
+        assert_eq!(
+            serde_json::to_value(LinuxSeccompFlag::Tsync).unwrap(),
+            "SECCOMP_FILTER_FLAG_TSYNC"
+        );
+
+        let err = "SCMP_ACT_allow".parse::<LinuxSeccompAction>().unwrap_err();
+        assert_eq!(err, ParseError::new("LinuxSeccompAction", "SCMP_ACT_allow"));
+        assert!("x86_64".parse::<Arch>().is_err());
+        assert!(serde_json::from_str::<LinuxSeccompFlag>(r#""TSYNC""#).is_err());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_syscall_arg_alias_still_compiles() {
+        let arg: LinuxSyscallArg = LinuxSyscallArgBuilder::default()
+            .index(1u64)
+            .value(2u64)
+            .op(LinuxSeccompOperator::NotEqual)
+            .build()
+            .unwrap();
+        let syscall = LinuxSyscallBuilder::default()
+            .names(vec!["clone".to_string()])
+            .args(vec![arg])
+            .build()
+            .unwrap();
+        assert_eq!(syscall.args()[0].value(), 2);
+    }
+
+    /// Deserializes `value` and asserts that serializing the result gives `value` back.
+    fn round_trip<T: Serialize + serde::de::DeserializeOwned>(value: serde_json::Value) -> T {
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), value);
+        parsed
+    }
+
+    #[test]
+    fn solaris_round_trips() {
+        let spec: Spec = round_trip(serde_json::json!({
+            "ociVersion": "1.2.1",
+            "solaris": {
+                "milestone": "svc:/milestone/container:default",
+                "limitpriv": "default",
+                "maxShmMemory": "512m",
+                "anet": [{
+                    "linkname": "net0",
+                    "lowerLink": "net2",
+                    "allowedAddress": "172.17.0.2/16",
+                    "configureAllowedAddress": "true",
+                    "defrouter": "172.17.0.1/16",
+                    "linkProtection": "mac-nospoof, ip-nospoof",
+                    "macAddress": "02:42:f8:52:c7:16"
+                }],
+                "cappedCPU": {"ncpus": "8"},
+                "cappedMemory": {"physical": "512m", "swap": "512m"}
+            }
+        }));
+        let solaris = spec.solaris().unwrap();
+        assert_eq!(
+            solaris.milestone(),
+            Some("svc:/milestone/container:default")
+        );
+        assert_eq!(solaris.limit_priv(), Some("default"));
+        assert_eq!(solaris.capped_cpu().unwrap().ncpus(), Some("8"));
+        assert_eq!(solaris.anet()[0].mac_address(), Some("02:42:f8:52:c7:16"));
+    }
+
+    #[test]
+    fn zos_round_trips() {
+        let spec: Spec = round_trip(serde_json::json!({
+            "ociVersion": "1.2.1",
+            "zos": {
+                "namespaces": [{"type": "pid"}, {"type": "mount", "path": "/proc/1/ns/mnt"}],
+                "devices": [{
+                    "path": "/dev/null",
+                    "type": "c",
+                    "major": 1,
+                    "minor": 3,
+                    "fileMode": 438,
+                    "uid": 0,
+                    "gid": 0
+                }]
+            }
+        }));
+        let zos = spec.zos().unwrap();
+        assert_eq!(zos.namespaces()[0].namespace_type(), ZOSNamespaceType::Pid);
+        assert_eq!(zos.namespaces()[1].path(), Some("/proc/1/ns/mnt"));
+        assert_eq!(zos.devices()[0].file_mode(), Some(0o666));
+    }
+
+    #[test]
+    fn v1_2_resource_fields_round_trip() {
+        let linux: Linux = round_trip(serde_json::json!({
+            "resources": {
+                "memory": {"limit": 1073741824, "checkBeforeUpdate": true},
+                "cpu": {"quota": 50000, "period": 100000, "burst": 10000, "idle": 1},
+                "pids": {"limit": 32768}
+            },
+            "intelRdt": {
+                "closID": "guaranteed_group",
+                "schemata": ["L3:0=7f0;1=1f", "MB:0=20;1=70"],
+                "enableCMT": true,
+                "enableMBM": false
+            }
+        }));
+        let resources = linux.resources().unwrap();
+        assert_eq!(
+            resources.memory().unwrap().check_before_update(),
+            Some(true)
+        );
+        assert_eq!(resources.cpu().unwrap().burst(), Some(10000));
+        assert_eq!(resources.cpu().unwrap().idle(), Some(1));
+        assert_eq!(resources.pids().unwrap().limit(), Some(32768));
+        let rdt = linux.intel_rdt().unwrap();
+        assert_eq!(rdt.schemata(), ["L3:0=7f0;1=1f", "MB:0=20;1=70"]);
+        assert_eq!(rdt.enable_cmt(), Some(true));
+        assert_eq!(rdt.enable_mbm(), Some(false));
+    }
+
+    #[test]
+    fn configs_without_v1_2_resource_fields_still_parse() {
+        let linux: Linux = round_trip(serde_json::json!({
+            "resources": {
+                "memory": {"limit": 1073741824},
+                "cpu": {"shares": 1024},
+                "pids": {}
+            },
+            "intelRdt": {"l3CacheSchema": "L3:0=7f0"}
+        }));
+        let resources = linux.resources().unwrap();
+        assert_eq!(resources.memory().unwrap().check_before_update(), None);
+        assert_eq!(resources.cpu().unwrap().burst(), None);
+        assert_eq!(resources.cpu().unwrap().idle(), None);
+        assert_eq!(resources.pids().unwrap().limit(), None);
+        let rdt = linux.intel_rdt().unwrap();
+        assert!(rdt.schemata().is_empty());
+        assert_eq!(rdt.enable_cmt(), None);
+        assert_eq!(rdt.enable_mbm(), None);
+    }
 }
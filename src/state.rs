@@ -0,0 +1,202 @@
+/*
+ * Copyright 2020 fsyncd, Berlin, Germany.
+ * Additional material, copyright of the containerd authors.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ParseError;
+
+string_enum! {
+    /// ContainerStatus is the lifecycle status of a container.
+    #[derive(Default)]
+    pub enum ContainerStatus {
+        /// The container is being created.
+        #[default]
+        Creating = "creating",
+        /// The runtime has finished the create operation.
+        Created = "created",
+        /// The container process has executed the user-specified program.
+        Running = "running",
+        /// The container process has exited.
+        Stopped = "stopped",
+    }
+}
+
+/// State holds information about the runtime state of the container.
+/// It is printed by the runtime's state operation and passed to hooks on stdin.
+#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]
+#[builder(default, setter(into))]
+pub struct State {
+    /// Version is the version of the specification that is supported.
+    #[serde(rename = "ociVersion")]
+    version: String,
+    /// ID is the container ID
+    id: String,
+    /// Status is the runtime status of the container.
+    status: ContainerStatus,
+    /// Pid is the process ID for the container process.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pid: Option<i32>,
+    /// Bundle is the path to the container's bundle directory.
+    bundle: String,
+    /// Annotations are key values associated with the container.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    annotations: HashMap<String, String>,
+}
+
+impl State {
+    /// Version is the version of the specification that is supported.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// ID is the container ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Status is the runtime status of the container.
+    pub fn status(&self) -> ContainerStatus {
+        self.status
+    }
+
+    /// Pid is the process ID for the container process.
+    pub fn pid(&self) -> Option<i32> {
+        self.pid
+    }
+
+    /// Bundle is the path to the container's bundle directory.
+    pub fn bundle(&self) -> &str {
+        &self.bundle
+    }
+
+    /// Annotations are key values associated with the container.
+    pub fn annotations(&self) -> &HashMap<String, String> {
+        &self.annotations
+    }
+
+    /// Mutable access to [`version`](Self::version).
+    pub fn version_mut(&mut self) -> &mut String {
+        &mut self.version
+    }
+
+    /// Mutable access to [`id`](Self::id).
+    pub fn id_mut(&mut self) -> &mut String {
+        &mut self.id
+    }
+
+    /// Mutable access to [`status`](Self::status).
+    pub fn status_mut(&mut self) -> &mut ContainerStatus {
+        &mut self.status
+    }
+
+    /// Mutable access to [`pid`](Self::pid).
+    pub fn pid_mut(&mut self) -> &mut Option<i32> {
+        &mut self.pid
+    }
+
+    /// Mutable access to [`bundle`](Self::bundle).
+    pub fn bundle_mut(&mut self) -> &mut String {
+        &mut self.bundle
+    }
+
+    /// Mutable access to [`annotations`](Self::annotations).
+    pub fn annotations_mut(&mut self) -> &mut HashMap<String, String> {
+        &mut self.annotations
+    }
+}
+
+impl_to_builder! {
+    State => StateBuilder { version, id, status, pid, bundle, annotations },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    /// Output of `runc state` for a running container.
+    const RUNC_STATE: &str = include_str!("../testdata/runc-state.json");
+
+    #[test]
+    fn runc_state_round_trips() {
+        let state: State = serde_json::from_str(RUNC_STATE).unwrap();
+        assert_eq!(state.version(), "1.2.1");
+        assert_eq!(state.id(), "demo");
+        assert_eq!(state.status(), ContainerStatus::Running);
+        assert_eq!(state.pid(), Some(41235));
+        assert_eq!(state.bundle(), "/home/user/demo");
+        assert_eq!(
+            state.annotations()["org.opencontainers.image.title"],
+            "demo"
+        );
+
+        // runc adds rootfs, created and owner, which are not part of the specification.
+        let mut expected: Value = serde_json::from_str(RUNC_STATE).unwrap();
+        for key in &["rootfs", "created", "owner"] {
+            expected.as_object_mut().unwrap().remove(*key);
+        }
+        assert_eq!(serde_json::to_value(&state).unwrap(), expected);
+    }
+
+    #[test]
+    fn pid_is_omitted_when_unset() {
+        let state: State = serde_json::from_value(json!({
+            "ociVersion": "1.2.1",
+            "id": "demo",
+            "status": "stopped",
+            "bundle": "/home/user/demo",
+        }))
+        .unwrap();
+        assert_eq!(state.pid(), None);
+        assert!(state.annotations().is_empty());
+        assert_eq!(
+            serde_json::to_value(&state).unwrap(),
+            json!({
+                "ociVersion": "1.2.1",
+                "id": "demo",
+                "status": "stopped",
+                "bundle": "/home/user/demo",
+            })
+        );
+    }
+
+    #[test]
+    fn status_values() {
+        let statuses = [
+            (ContainerStatus::Creating, "creating"),
+            (ContainerStatus::Created, "created"),
+            (ContainerStatus::Running, "running"),
+            (ContainerStatus::Stopped, "stopped"),
+        ];
+        for &(status, name) in statuses.iter() {
+            let state = StateBuilder::default()
+                .version("1.2.1")
+                .id("demo")
+                .status(status)
+                .bundle("/home/user/demo")
+                .build()
+                .unwrap();
+            let value = serde_json::to_value(&state).unwrap();
+            assert_eq!(value["status"], name);
+            let parsed: State = serde_json::from_value(value).unwrap();
+            assert_eq!(parsed.status(), status);
+            assert_eq!(name.parse::<ContainerStatus>().unwrap(), status);
+        }
+    }
+}
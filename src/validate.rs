@@ -0,0 +1,1576 @@
+/*
+ * Copyright 2020 fsyncd, Berlin, Germany.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::capability::validate_capabilities;
+use crate::{
+    FreeBSD, FreeBSDSharing, Hook, Hooks, Linux, LinuxNamespaceType, LinuxResources,
+    LinuxSchedulerPolicy, LinuxSeccomp, LinuxSeccompAction, Mount, Process, Scheduler, Spec,
+    Windows, VM,
+};
+use std::collections::HashSet;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// ValidationError describes a single violation of the runtime specification.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    path: String,
+    kind: ValidationErrorKind,
+}
+
+impl ValidationError {
+    /// Creates an error for the value at the given JSON pointer.
+    pub fn new<P: Into<String>>(path: P, kind: ValidationErrorKind) -> Self {
+        ValidationError {
+            path: path.into(),
+            kind,
+        }
+    }
+
+    /// Path is the JSON pointer (RFC 6901) of the offending value, e.g. `/linux/namespaces/2/type`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Kind describes what is wrong with the value.
+    pub fn kind(&self) -> &ValidationErrorKind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// ValidationErrorKind classifies a [`ValidationError`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+    /// A required value is missing or empty.
+    Missing,
+    /// A path that must be absolute is relative.
+    NotAbsolute,
+    /// A number lies outside of the inclusive range `min..=max`.
+    OutOfRange { min: i64, max: i64 },
+    /// A value that must be unique appears more than once.
+    Duplicate,
+    /// A value is malformed or not one of the allowed values.
+    Invalid(String),
+    /// A value contradicts another part of the configuration.
+    Conflict(String),
+}
+
+impl fmt::Display for ValidationErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationErrorKind::Missing => write!(f, "value is required"),
+            ValidationErrorKind::NotAbsolute => write!(f, "path must be absolute"),
+            ValidationErrorKind::OutOfRange { min, max } => {
+                write!(f, "value must be between {} and {}", min, max)
+            }
+            ValidationErrorKind::Duplicate => write!(f, "duplicate value"),
+            ValidationErrorKind::Invalid(reason) => write!(f, "invalid value: {}", reason),
+            ValidationErrorKind::Conflict(reason) => write!(f, "conflicting value: {}", reason),
+        }
+    }
+}
+
+/// Validator collects violations while walking a configuration.
+pub(crate) struct Validator {
+    errors: Vec<ValidationError>,
+    windows: bool,
+}
+
+impl Validator {
+    pub(crate) fn new() -> Self {
+        Validator {
+            errors: Vec::new(),
+            windows: false,
+        }
+    }
+
+    pub(crate) fn fail<P: Into<String>>(&mut self, path: P, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError::new(path, kind));
+    }
+
+    pub(crate) fn non_empty(&mut self, path: &str, value: &str) {
+        if value.is_empty() {
+            self.fail(path, ValidationErrorKind::Missing);
+        }
+    }
+
+    pub(crate) fn absolute(&mut self, path: &str, value: &str) {
+        if value.is_empty() {
+            self.fail(path, ValidationErrorKind::Missing);
+        } else if !self.is_absolute(value) {
+            self.fail(path, ValidationErrorKind::NotAbsolute);
+        }
+    }
+
+    fn is_absolute(&self, value: &str) -> bool {
+        value.starts_with('/') || (self.windows && is_windows_absolute(value))
+    }
+
+    pub(crate) fn range(&mut self, path: &str, value: i64, min: i64, max: i64) {
+        if value < min || value > max {
+            self.fail(path, ValidationErrorKind::OutOfRange { min, max });
+        }
+    }
+
+    pub(crate) fn one_of(&mut self, path: &str, value: &str, allowed: &[&str]) {
+        if !allowed.contains(&value) {
+            let reason = format!("{:?} is not one of {}", value, allowed.join(", "));
+            self.fail(path, ValidationErrorKind::Invalid(reason));
+        }
+    }
+
+    pub(crate) fn finish(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+/// Reports whether `value` is a drive-letter (`C:\`) or UNC (`\\server`) path.
+fn is_windows_absolute(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    value.starts_with("\\\\")
+        || (bytes.len() >= 3
+            && bytes[0].is_ascii_alphabetic()
+            && bytes[1] == b':'
+            && (bytes[2] == b'\\' || bytes[2] == b'/'))
+}
+
+/// Escapes a map key for use as a JSON pointer segment.
+fn pointer_key(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Reports whether `version` is a SemVer 2.0 version string.
+fn is_semver(version: &str) -> bool {
+    let version = version.split('+').next().unwrap_or_default();
+    let core = version.split('-').next().unwrap_or_default();
+    let parts: Vec<&str> = core.split('.').collect();
+    parts.len() == 3
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && part.chars().all(|c| c.is_ascii_digit())
+                && (part.len() == 1 || !part.starts_with('0'))
+        })
+}
+
+/// Reports whether `name` is a valid Linux network interface name.
+fn is_interface_name(name: &str) -> bool {
+    // IFNAMSIZ is 16, including the trailing NUL.
+    !name.is_empty()
+        && name.len() < 16
+        && name != "."
+        && name != ".."
+        && !name
+            .chars()
+            .any(|c| c == '/' || c == ':' || c.is_whitespace())
+}
+
+/// Reports whether `list` is a CPU list such as `0-3,7`.
+fn is_cpu_list(list: &str) -> bool {
+    let cpu = |s: &str| -> Option<u32> {
+        if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    };
+    list.split(',').all(|item| match item.find('-') {
+        Some(i) => match (cpu(&item[..i]), cpu(&item[i + 1..])) {
+            (Some(start), Some(end)) => start <= end,
+            _ => false,
+        },
+        None => cpu(item).is_some(),
+    })
+}
+
+impl Spec {
+    /// Checks the configuration against the MUST rules of the OCI runtime specification.
+    /// All violations are reported, not just the first one.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut v = Validator::new();
+        v.windows = self.windows.is_some();
+        if self.version.is_empty() {
+            v.fail("/ociVersion", ValidationErrorKind::Missing);
+        } else if !is_semver(&self.version) {
+            let reason = format!("{:?} is not a SemVer version", self.version);
+            v.fail("/ociVersion", ValidationErrorKind::Invalid(reason));
+        }
+        if let Some(root) = &self.root {
+            v.non_empty("/root/path", &root.path);
+        }
+        if let Some(process) = &self.process {
+            validate_process(&mut v, "/process", process);
+        }
+        let user_ns = self
+            .linux
+            .as_ref()
+            .is_some_and(|linux| linux.has_namespace(LinuxNamespaceType::User));
+        for (i, mount) in self.mounts.iter().enumerate() {
+            validate_mount(&mut v, &format!("/mounts/{}", i), mount, user_ns);
+        }
+        if let Some(hooks) = &self.hooks {
+            validate_hooks(&mut v, "/hooks", hooks);
+        }
+        if self.annotations.contains_key("") {
+            v.fail(
+                "/annotations/",
+                ValidationErrorKind::Invalid("empty key".into()),
+            );
+        }
+        if let Some(linux) = &self.linux {
+            validate_linux(&mut v, "/linux", linux);
+            let names = [
+                ("hostname", &self.hostname),
+                ("domainname", &self.domainname),
+            ];
+            for (name, value) in names.iter() {
+                let set = value.as_ref().is_some_and(|value| !value.is_empty());
+                if set && !linux.has_namespace(LinuxNamespaceType::Uts) {
+                    let reason = format!("{} requires a UTS namespace", name);
+                    v.fail(format!("/{}", name), ValidationErrorKind::Conflict(reason));
+                }
+            }
+        }
+        if let Some(windows) = &self.windows {
+            validate_windows(&mut v, "/windows", windows);
+        }
+        if let Some(vm) = &self.vm {
+            validate_vm(&mut v, "/vm", vm);
+        }
+        if let Some(freebsd) = &self.freebsd {
+            validate_freebsd(&mut v, "/freebsd", freebsd);
+        }
+        v.finish()
+    }
+}
+
+fn validate_process(v: &mut Validator, path: &str, process: &Process) {
+    if process.args.is_empty() && !(v.windows && process.command_line.is_some()) {
+        v.fail(format!("{}/args", path), ValidationErrorKind::Missing);
+    }
+    v.absolute(&format!("{}/cwd", path), &process.cwd);
+    for (i, env) in process.env.iter().enumerate() {
+        if !env.contains('=') {
+            let reason = format!("{:?} is not in KEY=value form", env);
+            v.fail(
+                format!("{}/env/{}", path, i),
+                ValidationErrorKind::Invalid(reason),
+            );
+        }
+    }
+    if let Some(capabilities) = &process.capabilities {
+        validate_capabilities(v, &format!("{}/capabilities", path), capabilities);
+    }
+    let mut rlimits = HashSet::new();
+    for (i, rlimit) in process.rlimits.iter().enumerate() {
+        let rlimit_path = format!("{}/rlimits/{}", path, i);
+        if !rlimits.insert(rlimit.rlimit_type.as_str()) {
+            v.fail(
+                format!("{}/type", rlimit_path),
+                ValidationErrorKind::Duplicate,
+            );
+        }
+        if rlimit.soft > rlimit.hard {
+            let reason = "soft limit exceeds hard limit".to_string();
+            v.fail(
+                format!("{}/soft", rlimit_path),
+                ValidationErrorKind::Invalid(reason),
+            );
+        }
+    }
+    if let Some(oom_score_adj) = process.oom_score_adj {
+        v.range(
+            &format!("{}/oomScoreAdj", path),
+            i64::from(oom_score_adj),
+            -1000,
+            1000,
+        );
+    }
+    if let Some(scheduler) = &process.scheduler {
+        validate_scheduler(v, &format!("{}/scheduler", path), scheduler);
+    }
+    if let Some(io_priority) = &process.io_priority {
+        v.range(
+            &format!("{}/ioPriority/priority", path),
+            i64::from(io_priority.priority),
+            0,
+            7,
+        );
+    }
+    if let Some(affinity) = &process.exec_cpu_affinity {
+        let lists = [
+            ("initial", &affinity.initial),
+            ("final", &affinity.final_cpus),
+        ];
+        for (name, list) in lists.iter() {
+            if let Some(list) = list {
+                if !is_cpu_list(list) {
+                    let reason = format!("{:?} is not a CPU list", list);
+                    v.fail(
+                        format!("{}/execCPUAffinity/{}", path, name),
+                        ValidationErrorKind::Invalid(reason),
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn validate_scheduler(v: &mut Validator, path: &str, scheduler: &Scheduler) {
+    let policy = scheduler.policy;
+    if let Some(nice) = scheduler.nice {
+        v.range(&format!("{}/nice", path), i64::from(nice), -20, 19);
+    }
+    match (policy, scheduler.priority) {
+        (LinuxSchedulerPolicy::Fifo, Some(priority))
+        | (LinuxSchedulerPolicy::Rr, Some(priority)) => {
+            v.range(&format!("{}/priority", path), i64::from(priority), 1, 99);
+        }
+        (_, Some(priority)) if priority != 0 => {
+            let reason = format!(
+                "priority is only supported by SCHED_FIFO and SCHED_RR, not {}",
+                policy
+            );
+            v.fail(
+                format!("{}/priority", path),
+                ValidationErrorKind::Conflict(reason),
+            );
+        }
+        _ => {}
+    }
+    let fields = [
+        ("runtime", scheduler.runtime),
+        ("deadline", scheduler.deadline),
+        ("period", scheduler.period),
+    ];
+    if policy != LinuxSchedulerPolicy::Deadline {
+        for (name, value) in fields.iter() {
+            if value.is_some() {
+                let reason = format!(
+                    "{} is only supported by SCHED_DEADLINE, not {}",
+                    name, policy
+                );
+                v.fail(
+                    format!("{}/{}", path, name),
+                    ValidationErrorKind::Conflict(reason),
+                );
+            }
+        }
+        return;
+    }
+    let runtime = scheduler.runtime.unwrap_or(0);
+    let deadline = scheduler.deadline.unwrap_or(0);
+    if runtime == 0 {
+        v.fail(format!("{}/runtime", path), ValidationErrorKind::Missing);
+    }
+    if deadline == 0 {
+        v.fail(format!("{}/deadline", path), ValidationErrorKind::Missing);
+    } else if runtime > deadline {
+        let reason = "runtime exceeds deadline".to_string();
+        v.fail(
+            format!("{}/runtime", path),
+            ValidationErrorKind::Invalid(reason),
+        );
+    }
+    // A zero or missing period defaults to the deadline.
+    match scheduler.period {
+        Some(period) if period != 0 && deadline > period => {
+            let reason = "deadline exceeds period".to_string();
+            v.fail(
+                format!("{}/deadline", path),
+                ValidationErrorKind::Invalid(reason),
+            );
+        }
+        _ => {}
+    }
+}
+
+fn validate_mount(v: &mut Validator, path: &str, mount: &Mount, user_ns: bool) {
+    v.absolute(&format!("{}/destination", path), &mount.destination);
+    let idmapped = !mount.uid_mappings.is_empty() || !mount.gid_mappings.is_empty();
+    if !idmapped {
+        return;
+    }
+    if !mount
+        .options
+        .iter()
+        .any(|option| option == "idmap" || option == "ridmap")
+    {
+        let reason = "ID mappings require the idmap or ridmap option".to_string();
+        v.fail(
+            format!("{}/options", path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    if mount.uid_mappings.is_empty() {
+        v.fail(
+            format!("{}/uidMappings", path),
+            ValidationErrorKind::Missing,
+        );
+    }
+    if mount.gid_mappings.is_empty() {
+        v.fail(
+            format!("{}/gidMappings", path),
+            ValidationErrorKind::Missing,
+        );
+    }
+    if !user_ns {
+        let reason = "ID-mapped mounts require a user namespace".to_string();
+        v.fail(path, ValidationErrorKind::Conflict(reason));
+    }
+}
+
+fn validate_hooks(v: &mut Validator, path: &str, hooks: &Hooks) {
+    let lists: [(&str, &[Hook]); 6] = [
+        ("prestart", &hooks.prestart),
+        ("createRuntime", &hooks.create_runtime),
+        ("createContainer", &hooks.create_container),
+        ("startContainer", &hooks.start_container),
+        ("poststart", &hooks.poststart),
+        ("poststop", &hooks.poststop),
+    ];
+    for (name, list) in lists.iter() {
+        for (i, hook) in list.iter().enumerate() {
+            validate_hook(v, &format!("{}/{}/{}", path, name, i), hook);
+        }
+    }
+}
+
+fn validate_hook(v: &mut Validator, path: &str, hook: &Hook) {
+    v.absolute(&format!("{}/path", path), &hook.path);
+    if let Some(timeout) = hook.timeout {
+        if timeout <= 0 {
+            v.fail(
+                format!("{}/timeout", path),
+                ValidationErrorKind::OutOfRange {
+                    min: 1,
+                    max: i64::from(i32::MAX),
+                },
+            );
+        }
+    }
+}
+
+fn validate_linux(v: &mut Validator, path: &str, linux: &Linux) {
+    let mut namespaces = HashSet::new();
+    for (i, namespace) in linux.namespaces.iter().enumerate() {
+        let ns_path = format!("{}/namespaces/{}", path, i);
+        if !namespaces.insert(namespace.namespace_type) {
+            v.fail(format!("{}/type", ns_path), ValidationErrorKind::Duplicate);
+        }
+        if let Some(ns) = &namespace.path {
+            v.absolute(&format!("{}/path", ns_path), ns);
+        }
+    }
+    for (i, device) in linux.devices.iter().enumerate() {
+        let device_path = format!("{}/devices/{}", path, i);
+        v.absolute(&format!("{}/path", device_path), &device.path);
+        v.one_of(
+            &format!("{}/type", device_path),
+            &device.device_type,
+            &["c", "b", "u", "p"],
+        );
+    }
+    if let Some(resources) = &linux.resources {
+        for (i, rule) in resources.devices.iter().enumerate() {
+            let rule_path = format!("{}/resources/devices/{}", path, i);
+            if let Some(device_type) = &rule.device_type {
+                v.one_of(
+                    &format!("{}/type", rule_path),
+                    device_type,
+                    &["a", "b", "c"],
+                );
+            }
+            if let Some(access) = &rule.access {
+                if access.is_empty() || !access.chars().all(|c| "rwm".contains(c)) {
+                    let reason = format!("{:?} is not a combination of r, w and m", access);
+                    v.fail(
+                        format!("{}/access", rule_path),
+                        ValidationErrorKind::Invalid(reason),
+                    );
+                }
+            }
+        }
+        validate_unified(v, &format!("{}/resources/unified", path), resources);
+    }
+    if let Some(propagation) = &linux.rootfs_propagation {
+        v.one_of(
+            &format!("{}/rootfsPropagation", path),
+            propagation,
+            &["shared", "slave", "private", "unbindable"],
+        );
+    }
+    for (i, masked) in linux.masked_paths.iter().enumerate() {
+        v.absolute(&format!("{}/maskedPaths/{}", path, i), masked);
+    }
+    for (i, readonly) in linux.readonly_paths.iter().enumerate() {
+        v.absolute(&format!("{}/readonlyPaths/{}", path, i), readonly);
+    }
+    if let Some(seccomp) = &linux.seccomp {
+        validate_seccomp(v, &format!("{}/seccomp", path), seccomp);
+    }
+    if let Some(personality) = &linux.personality {
+        v.one_of(
+            &format!("{}/personality/domain", path),
+            &personality.domain,
+            &["LINUX", "LINUX32"],
+        );
+    }
+    let new_network_ns = linux
+        .namespace(LinuxNamespaceType::Network)
+        .is_some_and(|namespace| namespace.path.is_none());
+    if !linux.net_devices.is_empty() && !new_network_ns {
+        let reason = "network devices require a new network namespace".to_string();
+        v.fail(
+            format!("{}/netDevices", path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    for (host_name, device) in &linux.net_devices {
+        let device_path = format!("{}/netDevices/{}", path, pointer_key(host_name));
+        if !is_interface_name(host_name) {
+            let reason = format!("{:?} is not a network interface name", host_name);
+            v.fail(&device_path, ValidationErrorKind::Invalid(reason));
+        }
+        if let Some(name) = device
+            .name
+            .as_deref()
+            .filter(|name| !is_interface_name(name))
+        {
+            let reason = format!("{:?} is not a network interface name", name);
+            v.fail(
+                format!("{}/name", device_path),
+                ValidationErrorKind::Invalid(reason),
+            );
+        }
+    }
+    // Offsets can only be written before the first process enters a new time namespace.
+    let new_time_ns = linux
+        .namespace(LinuxNamespaceType::Time)
+        .is_some_and(|namespace| namespace.path.is_none());
+    if !linux.time_offsets.is_empty() && !new_time_ns {
+        let reason = "time offsets require a new time namespace".to_string();
+        v.fail(
+            format!("{}/timeOffsets", path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    for (clock, offset) in &linux.time_offsets {
+        let offset_path = format!("{}/timeOffsets/{}", path, pointer_key(clock));
+        v.one_of(&offset_path, clock, &["monotonic", "boottime"]);
+        if offset.nanosecs >= 1_000_000_000 {
+            v.fail(
+                format!("{}/nanosecs", offset_path),
+                ValidationErrorKind::OutOfRange {
+                    min: 0,
+                    max: 999_999_999,
+                },
+            );
+        }
+    }
+}
+
+fn validate_windows(v: &mut Validator, path: &str, windows: &Windows) {
+    if windows.layer_folders.is_empty() {
+        v.fail(
+            format!("{}/layerFolders", path),
+            ValidationErrorKind::Missing,
+        );
+    }
+    for (i, folder) in windows.layer_folders.iter().enumerate() {
+        v.absolute(&format!("{}/layerFolders/{}", path, i), folder);
+    }
+    for (i, device) in windows.devices.iter().enumerate() {
+        let device_path = format!("{}/devices/{}", path, i);
+        v.non_empty(&format!("{}/id", device_path), &device.id);
+        v.one_of(
+            &format!("{}/idType", device_path),
+            &device.id_type,
+            &["class"],
+        );
+    }
+    if let Some(utility_vm_path) = windows
+        .hyperv
+        .as_ref()
+        .and_then(|h| h.utility_vm_path.as_ref())
+    {
+        v.absolute(&format!("{}/hyperv/utilityVMPath", path), utility_vm_path);
+    }
+}
+
+fn validate_vm(v: &mut Validator, path: &str, vm: &VM) {
+    if let Some(hypervisor) = &vm.hypervisor {
+        v.absolute(&format!("{}/hypervisor/path", path), &hypervisor.path);
+    }
+    v.absolute(&format!("{}/kernel/path", path), &vm.kernel.path);
+    if let Some(init_rd) = &vm.kernel.init_rd {
+        v.absolute(&format!("{}/kernel/initrd", path), init_rd);
+    }
+    if let Some(image) = &vm.image {
+        v.absolute(&format!("{}/image/path", path), &image.path);
+        v.one_of(
+            &format!("{}/image/format", path),
+            &image.format,
+            &["raw", "qcow2", "vdi", "vmdk", "vhd"],
+        );
+    }
+}
+
+/// File system types that have an `allow.mount.<type>` jail parameter.
+const FREEBSD_JAIL_MOUNT_TYPES: &[&str] = &[
+    "devfs",
+    "fdescfs",
+    "fusefs",
+    "lindebugfs",
+    "linprocfs",
+    "linsysfs",
+    "nullfs",
+    "procfs",
+    "tmpfs",
+    "zfs",
+];
+
+fn validate_freebsd(v: &mut Validator, path: &str, freebsd: &FreeBSD) {
+    for (i, device) in freebsd.devices.iter().enumerate() {
+        let device_path = format!("{}/devices/{}/path", path, i);
+        v.non_empty(&device_path, &device.path);
+        if device.path.starts_with('/') {
+            let reason = "device paths are relative to /dev".to_string();
+            v.fail(device_path, ValidationErrorKind::Invalid(reason));
+        }
+    }
+    let jail = match &freebsd.jail {
+        Some(jail) => jail,
+        None => return,
+    };
+    let jail_path = format!("{}/jail", path);
+    if let Some(parent) = &jail.parent {
+        if parent.is_empty() || parent.chars().all(|c| c.is_ascii_digit()) {
+            let reason = format!("{:?} is not a valid jail name", parent);
+            v.fail(
+                format!("{}/parent", jail_path),
+                ValidationErrorKind::Invalid(reason),
+            );
+        }
+    }
+    for (i, addr) in jail.ip4_addr.iter().enumerate() {
+        if addr.parse::<Ipv4Addr>().is_err() {
+            let reason = format!("{:?} is not an IPv4 address", addr);
+            v.fail(
+                format!("{}/ip4Addr/{}", jail_path, i),
+                ValidationErrorKind::Invalid(reason),
+            );
+        }
+    }
+    for (i, addr) in jail.ip6_addr.iter().enumerate() {
+        if addr.parse::<Ipv6Addr>().is_err() {
+            let reason = format!("{:?} is not an IPv6 address", addr);
+            v.fail(
+                format!("{}/ip6Addr/{}", jail_path, i),
+                ValidationErrorKind::Invalid(reason),
+            );
+        }
+    }
+    if !jail.ip4_addr.is_empty() && jail.ip4.is_some_and(|ip| ip != FreeBSDSharing::New) {
+        let reason = "ip4Addr requires ip4 to be \"new\"".to_string();
+        v.fail(
+            format!("{}/ip4", jail_path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    if !jail.ip6_addr.is_empty() && jail.ip6.is_some_and(|ip| ip != FreeBSDSharing::New) {
+        let reason = "ip6Addr requires ip6 to be \"new\"".to_string();
+        v.fail(
+            format!("{}/ip6", jail_path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    if !jail.vnet_interfaces.is_empty() && jail.vnet != Some(FreeBSDSharing::New) {
+        let reason = "vnetInterfaces requires vnet to be \"new\"".to_string();
+        v.fail(
+            format!("{}/vnet", jail_path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    if let Some(enforce_statfs) = jail.enforce_statfs {
+        v.range(
+            &format!("{}/enforceStatfs", jail_path),
+            i64::from(enforce_statfs),
+            0,
+            2,
+        );
+    }
+    if let Some(allow) = &jail.allow {
+        for (i, mount) in allow.mount.iter().enumerate() {
+            v.one_of(
+                &format!("{}/allow/mount/{}", jail_path, i),
+                mount,
+                FREEBSD_JAIL_MOUNT_TYPES,
+            );
+        }
+    }
+}
+
+fn validate_seccomp(v: &mut Validator, path: &str, seccomp: &LinuxSeccomp) {
+    let returns_errno = |action: LinuxSeccompAction| {
+        action == LinuxSeccompAction::Errno || action == LinuxSeccompAction::Trace
+    };
+    if seccomp.default_action == LinuxSeccompAction::Notify {
+        let reason = "SCMP_ACT_NOTIFY cannot be the default action".to_string();
+        v.fail(
+            format!("{}/defaultAction", path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    if seccomp.default_errno_ret.is_some() && !returns_errno(seccomp.default_action) {
+        let reason = format!(
+            "errno return is not supported by {}",
+            seccomp.default_action
+        );
+        v.fail(
+            format!("{}/defaultErrnoRet", path),
+            ValidationErrorKind::Conflict(reason),
+        );
+    }
+    match &seccomp.listener_path {
+        Some(listener_path) => v.absolute(&format!("{}/listenerPath", path), listener_path),
+        None => {
+            if seccomp.listener_metadata.is_some() {
+                let reason = "listener metadata requires a listener path".to_string();
+                v.fail(
+                    format!("{}/listenerMetadata", path),
+                    ValidationErrorKind::Conflict(reason),
+                );
+            }
+            if seccomp
+                .syscalls
+                .iter()
+                .any(|syscall| syscall.action == LinuxSeccompAction::Notify)
+            {
+                v.fail(
+                    format!("{}/listenerPath", path),
+                    ValidationErrorKind::Missing,
+                );
+            }
+        }
+    }
+    for (i, syscall) in seccomp.syscalls.iter().enumerate() {
+        let syscall_path = format!("{}/syscalls/{}", path, i);
+        if syscall.names.is_empty() {
+            v.fail(
+                format!("{}/names", syscall_path),
+                ValidationErrorKind::Missing,
+            );
+        }
+        if syscall.errno_ret.is_some() && !returns_errno(syscall.action) {
+            let reason = format!("errno return is not supported by {}", syscall.action);
+            v.fail(
+                format!("{}/errnoRet", syscall_path),
+                ValidationErrorKind::Conflict(reason),
+            );
+        }
+    }
+}
+
+fn validate_unified(v: &mut Validator, path: &str, resources: &LinuxResources) {
+    for (key, value) in &resources.unified {
+        let key_path = format!("{}/{}", path, pointer_key(key));
+        let well_formed = match key.split_once('.') {
+            Some((controller, file)) => {
+                !controller.is_empty()
+                    && controller
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+                    && !file.is_empty()
+                    && !file.contains('/')
+                    && !file.chars().any(char::is_whitespace)
+            }
+            None => false,
+        };
+        if !well_formed {
+            let reason = format!("{:?} is not of the form <controller>.<file>", key);
+            v.fail(key_path, ValidationErrorKind::Invalid(reason));
+        } else if let Some(field) = v1_conflict(resources, key) {
+            let reason = format!("{} is also set by resources/{}", key, field);
+            v.fail(key_path, ValidationErrorKind::Conflict(reason));
+        } else if let Some((min, max)) = unified_range(key) {
+            match value.trim().parse::<i64>() {
+                Ok(value) => v.range(&key_path, value, min, max),
+                Err(_) => {
+                    let reason = format!("{:?} is not an integer", value);
+                    v.fail(key_path, ValidationErrorKind::Invalid(reason));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the accepted range of the integer-valued unified `key`, if it has one.
+fn unified_range(key: &str) -> Option<(i64, i64)> {
+    match key {
+        "cpu.weight" => Some((1, 10000)),
+        "cpu.weight.nice" => Some((-20, 19)),
+        _ => None,
+    }
+}
+
+/// Returns the cgroup v1 field that configures the same setting as the unified `key`,
+/// if that field is set.
+fn v1_conflict(resources: &LinuxResources, key: &str) -> Option<&'static str> {
+    let memory = resources.memory.as_ref();
+    let cpu = resources.cpu.as_ref();
+    let block_io = resources.block_io.as_ref();
+    let set = match key {
+        "memory.max" => memory.and_then(|m| m.limit).map(|_| "memory/limit"),
+        "memory.low" => memory
+            .and_then(|m| m.reservation)
+            .map(|_| "memory/reservation"),
+        "memory.swap.max" => memory.and_then(|m| m.swap).map(|_| "memory/swap"),
+        "cpu.weight" | "cpu.weight.nice" => cpu.and_then(|c| c.shares).map(|_| "cpu/shares"),
+        "cpu.max" => cpu
+            .filter(|c| c.quota.is_some() || c.period.is_some())
+            .map(|_| "cpu/quota"),
+        "cpuset.cpus" => cpu.and_then(|c| c.cpus.as_ref()).map(|_| "cpu/cpus"),
+        "cpuset.mems" => cpu.and_then(|c| c.mems.as_ref()).map(|_| "cpu/mems"),
+        "cpu.max.burst" => cpu.and_then(|c| c.burst).map(|_| "cpu/burst"),
+        "cpu.idle" => cpu.and_then(|c| c.idle).map(|_| "cpu/idle"),
+        "pids.max" => resources
+            .pids
+            .as_ref()
+            .and_then(|p| p.limit)
+            .map(|_| "pids/limit"),
+        "io.weight" => block_io.and_then(|b| b.weight).map(|_| "blockIO/weight"),
+        "io.max" => block_io
+            .filter(|b| {
+                !b.throttle_read_bps_device.is_empty()
+                    || !b.throttle_write_bps_device.is_empty()
+                    || !b.throttle_read_iops_device.is_empty()
+                    || !b.throttle_write_iops_device.is_empty()
+            })
+            .map(|_| "blockIO/throttle*Device"),
+        "rdma.max" if !resources.rdma.is_empty() => Some("rdma"),
+        _ => None,
+    };
+    set.or_else(|| {
+        let page_size = key.strip_prefix("hugetlb.")?.strip_suffix(".max")?;
+        resources
+            .hugepage_limits
+            .iter()
+            .find(|limit| limit.page_size == page_size)
+            .map(|_| "hugepageLimits")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    /// Applies `patch` to `target` as a JSON merge patch (RFC 7386).
+    fn merge(target: &mut Value, patch: Value) {
+        match patch {
+            Value::Object(patch) => {
+                if !target.is_object() {
+                    *target = json!({});
+                }
+                let target = target.as_object_mut().unwrap();
+                for (key, value) in patch {
+                    if value.is_null() {
+                        target.remove(&key);
+                    } else {
+                        merge(target.entry(key).or_insert(Value::Null), value);
+                    }
+                }
+            }
+            patch => *target = patch,
+        }
+    }
+
+    /// Returns `Spec::default_linux()` with `patch` merged into it.
+    fn spec(patch: Value) -> Spec {
+        let mut value = serde_json::to_value(Spec::default_linux()).unwrap();
+        merge(&mut value, patch);
+        serde_json::from_value(value).unwrap()
+    }
+
+    /// Returns the sorted JSON pointers of all violations in `spec`.
+    fn paths(spec: &Spec) -> Vec<String> {
+        let mut paths: Vec<String> = match spec.validate() {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors.iter().map(|e| e.path().to_string()).collect(),
+        };
+        paths.sort();
+        paths
+    }
+
+    /// Returns the namespaces of `Spec::default_linux()` followed by `extra`.
+    fn namespaces(extra: Value) -> Value {
+        let linux = Spec::default_linux().linux.unwrap();
+        let mut namespaces = serde_json::to_value(linux.namespaces).unwrap();
+        let list = namespaces.as_array_mut().unwrap();
+        list.extend(extra.as_array().unwrap().iter().cloned());
+        namespaces
+    }
+
+    fn resources(spec: &mut Spec) -> &mut LinuxResources {
+        let linux = spec.linux_mut().as_mut().unwrap();
+        linux.resources_mut().get_or_insert_with(Default::default)
+    }
+
+    #[test]
+    fn default_linux_is_valid() {
+        assert_eq!(paths(&Spec::default_linux()), Vec::<String>::new());
+    }
+
+    #[test]
+    fn oci_version() {
+        assert!(spec(json!({"ociVersion": "1.2.1-rc.1+dev"}))
+            .validate()
+            .is_ok());
+        assert_eq!(paths(&spec(json!({"ociVersion": ""}))), ["/ociVersion"]);
+        assert_eq!(paths(&spec(json!({"ociVersion": "1.2"}))), ["/ociVersion"]);
+        assert_eq!(
+            paths(&spec(json!({"ociVersion": "01.2.0"}))),
+            ["/ociVersion"]
+        );
+    }
+
+    #[test]
+    fn root_path() {
+        assert!(spec(json!({"root": {"path": "/var/lib/rootfs"}}))
+            .validate()
+            .is_ok());
+        assert_eq!(paths(&spec(json!({"root": {"path": ""}}))), ["/root/path"]);
+    }
+
+    #[test]
+    fn process_args_cwd_and_env() {
+        let valid = spec(json!({"process": {"env": ["A=", "B=c=d"], "cwd": "/work"}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"process": {"args": [], "cwd": "work", "env": ["A=1", "B"]}}));
+        assert_eq!(
+            paths(&invalid),
+            ["/process/args", "/process/cwd", "/process/env/1"]
+        );
+        let no_cwd = spec(json!({"process": {"cwd": ""}}));
+        assert_eq!(paths(&no_cwd), ["/process/cwd"]);
+    }
+
+    #[test]
+    fn process_capabilities() {
+        let invalid = spec(json!({"process": {"capabilities": {
+            "effective": ["CAP_KILL", "CAP_SYS_ADMIN"],
+            "ambient": ["CAP_KILL"],
+        }}}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/process/capabilities/ambient/0",
+                "/process/capabilities/effective/1",
+            ]
+        );
+    }
+
+    #[test]
+    fn process_rlimits() {
+        let valid = spec(json!({"process": {"rlimits": [
+            {"type": "RLIMIT_NOFILE", "hard": 1024, "soft": 1024},
+            {"type": "RLIMIT_CORE", "hard": 0, "soft": 0},
+        ]}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"process": {"rlimits": [
+            {"type": "RLIMIT_NOFILE", "hard": 1024, "soft": 1024},
+            {"type": "RLIMIT_NOFILE", "hard": 1, "soft": 2},
+        ]}}));
+        assert_eq!(
+            paths(&invalid),
+            ["/process/rlimits/1/soft", "/process/rlimits/1/type"]
+        );
+    }
+
+    #[test]
+    fn process_oom_score_adj() {
+        assert!(spec(json!({"process": {"oomScoreAdj": -1000}}))
+            .validate()
+            .is_ok());
+        let invalid = spec(json!({"process": {"oomScoreAdj": 1001}}));
+        assert_eq!(paths(&invalid), ["/process/oomScoreAdj"]);
+    }
+
+    #[test]
+    fn process_scheduler() {
+        for scheduler in &[
+            json!({"policy": "SCHED_FIFO", "priority": 50, "nice": -20}),
+            json!({"policy": "SCHED_OTHER", "nice": 19, "flags": ["SCHED_FLAG_RESET_ON_FORK"]}),
+            json!({"policy": "SCHED_DEADLINE", "runtime": 10, "deadline": 20, "period": 30}),
+            json!({"policy": "SCHED_DEADLINE", "runtime": 10, "deadline": 20}),
+        ] {
+            let valid = spec(json!({"process": {"scheduler": scheduler}}));
+            assert!(valid.validate().is_ok(), "{}", scheduler);
+        }
+        let cases = [
+            (
+                json!({"policy": "SCHED_RR", "priority": 0, "nice": 20}),
+                vec!["/process/scheduler/nice", "/process/scheduler/priority"],
+            ),
+            (
+                json!({"policy": "SCHED_BATCH", "priority": 1, "period": 5}),
+                vec!["/process/scheduler/period", "/process/scheduler/priority"],
+            ),
+            (
+                json!({"policy": "SCHED_DEADLINE", "runtime": 30, "deadline": 20, "period": 10}),
+                vec!["/process/scheduler/deadline", "/process/scheduler/runtime"],
+            ),
+            (
+                json!({"policy": "SCHED_DEADLINE"}),
+                vec!["/process/scheduler/deadline", "/process/scheduler/runtime"],
+            ),
+        ];
+        for (scheduler, expected) in cases.iter() {
+            let invalid = spec(json!({"process": {"scheduler": scheduler}}));
+            assert_eq!(&paths(&invalid), expected, "{}", scheduler);
+        }
+    }
+
+    #[test]
+    fn process_io_priority_and_cpu_affinity() {
+        let valid = spec(json!({"process": {
+            "ioPriority": {"class": "IOPRIO_CLASS_IDLE", "priority": 7},
+            "execCPUAffinity": {"initial": "0-3,7", "final": "5"},
+        }}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"process": {
+            "ioPriority": {"class": "IOPRIO_CLASS_RT", "priority": 8},
+            "execCPUAffinity": {"initial": "3-1", "final": "0,"},
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/process/execCPUAffinity/final",
+                "/process/execCPUAffinity/initial",
+                "/process/ioPriority/priority",
+            ]
+        );
+    }
+
+    #[test]
+    fn windows_command_line_replaces_args() {
+        let windows = json!({"layerFolders": ["C:\\layers\\base"]});
+        let valid = spec(json!({
+            "process": {"args": [], "commandLine": "cmd /c dir", "cwd": "C:\\"},
+            "windows": windows,
+        }));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"process": {"args": [], "commandLine": "cmd /c dir"}}));
+        assert_eq!(paths(&invalid), ["/process/args"]);
+    }
+
+    #[test]
+    fn mount_destination() {
+        let invalid = spec(json!({"mounts": [{"destination": "tmp", "type": "tmpfs"}]}));
+        assert_eq!(paths(&invalid), ["/mounts/0/destination"]);
+    }
+
+    #[test]
+    fn mount_id_mappings() {
+        let mapping = json!([{"containerID": 0, "hostID": 100000, "size": 65536}]);
+        let idmapped = |options: Value, uid: &Value, gid: &Value, namespaces: Value| {
+            spec(json!({
+                "mounts": [{
+                    "destination": "/data",
+                    "type": "bind",
+                    "source": "/srv/data",
+                    "options": options,
+                    "uidMappings": uid,
+                    "gidMappings": gid,
+                }],
+                "linux": {"namespaces": namespaces},
+            }))
+        };
+        let user_ns = namespaces(json!([{"type": "user"}]));
+        let valid = idmapped(
+            json!(["rbind", "idmap"]),
+            &mapping,
+            &mapping,
+            user_ns.clone(),
+        );
+        assert!(valid.validate().is_ok());
+        let ridmap = idmapped(
+            json!(["rbind", "ridmap"]),
+            &mapping,
+            &mapping,
+            user_ns.clone(),
+        );
+        assert!(ridmap.validate().is_ok());
+
+        let no_option = idmapped(json!(["rbind"]), &mapping, &json!([]), user_ns);
+        assert_eq!(
+            paths(&no_option),
+            ["/mounts/0/gidMappings", "/mounts/0/options"]
+        );
+        let no_user_ns = idmapped(json!(["idmap"]), &mapping, &mapping, namespaces(json!([])));
+        assert_eq!(paths(&no_user_ns), ["/mounts/0"]);
+    }
+
+    #[test]
+    fn hooks() {
+        let valid = spec(json!({"hooks": {
+            "createRuntime": [{"path": "/usr/bin/setup-network", "timeout": 10}],
+            "poststart": ["/usr/bin/notify"],
+        }}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"hooks": {
+            "prestart": [{"path": "setup"}],
+            "startContainer": [{"path": "/bin/true", "timeout": 0}],
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            ["/hooks/prestart/0/path", "/hooks/startContainer/0/timeout"]
+        );
+    }
+
+    #[test]
+    fn annotations() {
+        let valid = spec(json!({"annotations": {"org.example.key": ""}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"annotations": {"": "value"}}));
+        assert_eq!(paths(&invalid), ["/annotations/"]);
+    }
+
+    #[test]
+    fn hostname_and_domainname_require_uts_namespace() {
+        let valid = spec(json!({"hostname": "box", "domainname": "example.com"}));
+        assert!(valid.validate().is_ok());
+        let no_uts = namespaces(json!([]))
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|ns| ns["type"] != "uts")
+            .cloned()
+            .collect::<Vec<_>>();
+        let invalid = spec(json!({
+            "hostname": "box",
+            "domainname": "example.com",
+            "linux": {"namespaces": no_uts},
+        }));
+        assert_eq!(paths(&invalid), ["/domainname", "/hostname"]);
+        let empty = spec(json!({"hostname": "", "linux": {"namespaces": no_uts}}));
+        assert!(empty.validate().is_ok());
+    }
+
+    #[test]
+    fn linux_namespaces() {
+        let valid = spec(json!({"linux": {
+            "namespaces": namespaces(json!([{"type": "user", "path": "/proc/1/ns/user"}])),
+        }}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"linux": {
+            "namespaces": namespaces(json!([{"type": "pid", "path": "proc/1/ns/pid"}])),
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            ["/linux/namespaces/6/path", "/linux/namespaces/6/type"]
+        );
+    }
+
+    #[test]
+    fn linux_devices() {
+        let valid = spec(json!({"linux": {"devices": [
+            {"path": "/dev/fuse", "type": "c", "major": 10, "minor": 229},
+        ]}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"linux": {"devices": [
+            {"path": "dev/fuse", "type": "x", "major": 10, "minor": 229},
+        ]}}));
+        assert_eq!(
+            paths(&invalid),
+            ["/linux/devices/0/path", "/linux/devices/0/type"]
+        );
+    }
+
+    #[test]
+    fn linux_device_cgroup_rules() {
+        let valid = spec(json!({"linux": {"resources": {"devices": [
+            {"allow": false, "access": "rwm"},
+            {"allow": true, "type": "c", "major": 10, "minor": 229, "access": "rw"},
+        ]}}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"linux": {"resources": {"devices": [
+            {"allow": true, "type": "u", "access": ""},
+            {"allow": true, "type": "a", "access": "rx"},
+        ]}}}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/linux/resources/devices/0/access",
+                "/linux/resources/devices/0/type",
+                "/linux/resources/devices/1/access",
+            ]
+        );
+    }
+
+    #[test]
+    fn linux_paths_and_propagation() {
+        let valid = spec(json!({"linux": {"rootfsPropagation": "rslave"}}));
+        assert_eq!(paths(&valid), ["/linux/rootfsPropagation"]);
+        let valid = spec(json!({"linux": {"rootfsPropagation": "slave"}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"linux": {
+            "maskedPaths": ["/proc/kcore", "proc/keys"],
+            "readonlyPaths": [""],
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            ["/linux/maskedPaths/1", "/linux/readonlyPaths/0"]
+        );
+    }
+
+    #[test]
+    fn linux_personality() {
+        let valid = spec(json!({"linux": {"personality": {"domain": "LINUX32"}}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"linux": {"personality": {"domain": "LINUX64"}}}));
+        assert_eq!(paths(&invalid), ["/linux/personality/domain"]);
+    }
+
+    #[test]
+    fn linux_net_devices() {
+        let valid = spec(json!({"linux": {"netDevices": {"eth1": {"name": "ctr0"}, "eth2": {}}}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"linux": {
+            "netDevices": {"eth/1": {"name": "an-overly-long-name"}},
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            ["/linux/netDevices/eth~11", "/linux/netDevices/eth~11/name"]
+        );
+        let joined = spec(json!({"linux": {
+            "netDevices": {"eth1": {}},
+            "namespaces": [{"type": "network", "path": "/var/run/netns/ctr"}, {"type": "uts"}],
+        }}));
+        assert_eq!(paths(&joined), ["/linux/netDevices"]);
+    }
+
+    #[test]
+    fn unified_keys() {
+        let valid = spec(json!({"linux": {"resources": {
+            "memory": {"limit": 1 << 30},
+            "unified": {"memory.high": "max", "io.weight": "default 100", "hugetlb.2MB.max": "0"},
+        }}}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"linux": {"resources": {
+            "unified": {"memory": "1", "Memory.max": "1", "memory.": "1", "cpu.max x": "1"},
+        }}}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/linux/resources/unified/Memory.max",
+                "/linux/resources/unified/cpu.max x",
+                "/linux/resources/unified/memory",
+                "/linux/resources/unified/memory.",
+            ]
+        );
+    }
+
+    #[test]
+    fn unified_conflicts_with_v1_fields() {
+        let invalid = spec(json!({"linux": {"resources": {
+            "memory": {"limit": 1 << 30},
+            "pids": {"limit": 100},
+            "hugepageLimits": [{"pageSize": "2MB", "limit": 0}],
+            "unified": {"memory.max": "max", "pids.max": "100", "hugetlb.2MB.max": "0"},
+        }}}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/linux/resources/unified/hugetlb.2MB.max",
+                "/linux/resources/unified/memory.max",
+                "/linux/resources/unified/pids.max",
+            ]
+        );
+        let unset_pids = spec(json!({"linux": {"resources": {
+            "pids": {},
+            "unified": {"pids.max": "100"},
+        }}}));
+        assert!(unset_pids.validate().is_ok());
+    }
+
+    #[test]
+    fn windows_section() {
+        let valid = spec(json!({
+            "process": {"cwd": "C:\\"},
+            "windows": {
+                "layerFolders": ["C:\\layers\\base", "\\\\server\\share\\layer"],
+                "devices": [{"id": "24e552d7-6523-47f7-a647-d3465bf1f5ca", "idType": "class"}],
+                "hyperv": {"utilityVMPath": "C:\\uvm"},
+            },
+        }));
+        assert!(valid.validate().is_ok());
+        let missing = spec(json!({"windows": {}}));
+        assert_eq!(paths(&missing), ["/windows/layerFolders"]);
+        let invalid = spec(json!({"windows": {
+            "layerFolders": ["layers\\base"],
+            "devices": [{"id": "", "idType": "vpci"}],
+            "hyperv": {"utilityVMPath": "uvm"},
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/windows/devices/0/id",
+                "/windows/devices/0/idType",
+                "/windows/hyperv/utilityVMPath",
+                "/windows/layerFolders/0",
+            ]
+        );
+    }
+
+    #[test]
+    fn vm_section() {
+        let valid = spec(json!({"vm": {
+            "hypervisor": {"path": "/usr/bin/qemu-system-x86_64"},
+            "kernel": {"path": "/var/lib/vm/vmlinuz", "initrd": "/var/lib/vm/initrd.img"},
+            "image": {"path": "/var/lib/vm/disk.qcow2", "format": "qcow2"},
+        }}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"vm": {
+            "hypervisor": {"path": "qemu"},
+            "kernel": {"path": "", "initrd": "initrd.img"},
+            "image": {"path": "disk.img", "format": "iso"},
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/vm/hypervisor/path",
+                "/vm/image/format",
+                "/vm/image/path",
+                "/vm/kernel/initrd",
+                "/vm/kernel/path",
+            ]
+        );
+    }
+
+    #[test]
+    fn freebsd_section() {
+        let valid = spec(json!({"freebsd": {
+            "devices": [{"path": "pf"}],
+            "jail": {
+                "parent": "build",
+                "ip4": "new",
+                "ip4Addr": ["192.0.2.10"],
+                "ip6Addr": ["2001:db8::10"],
+                "vnet": "new",
+                "vnetInterfaces": ["epair0b"],
+                "enforceStatfs": 2,
+                "allow": {"mount": ["nullfs", "tmpfs"]},
+            },
+        }}));
+        assert!(valid.validate().is_ok());
+        let invalid = spec(json!({"freebsd": {
+            "devices": [{"path": "/dev/pf"}, {"path": ""}],
+            "jail": {
+                "parent": "42",
+                "ip4": "inherit",
+                "ip4Addr": ["192.0.2.300"],
+                "ip6": "disable",
+                "ip6Addr": ["2001:db8::g"],
+                "vnetInterfaces": ["epair0b"],
+                "enforceStatfs": 3,
+                "allow": {"mount": ["ext4"]},
+            },
+        }}));
+        assert_eq!(
+            paths(&invalid),
+            [
+                "/freebsd/devices/0/path",
+                "/freebsd/devices/1/path",
+                "/freebsd/jail/allow/mount/0",
+                "/freebsd/jail/enforceStatfs",
+                "/freebsd/jail/ip4",
+                "/freebsd/jail/ip4Addr/0",
+                "/freebsd/jail/ip6",
+                "/freebsd/jail/ip6Addr/0",
+                "/freebsd/jail/parent",
+                "/freebsd/jail/vnet",
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_carry_their_kind() {
+        let invalid = spec(json!({
+            "ociVersion": "",
+            "process": {"cwd": "work", "oomScoreAdj": 2000, "rlimits": [
+                {"type": "RLIMIT_NOFILE", "hard": 1, "soft": 1},
+                {"type": "RLIMIT_NOFILE", "hard": 1, "soft": 1},
+            ]},
+        }));
+        let errors = invalid.validate().unwrap_err();
+        let kind = |path: &str| errors.iter().find(|e| e.path() == path).unwrap().kind();
+        assert_eq!(kind("/ociVersion"), &ValidationErrorKind::Missing);
+        assert_eq!(kind("/process/cwd"), &ValidationErrorKind::NotAbsolute);
+        assert_eq!(
+            kind("/process/oomScoreAdj"),
+            &ValidationErrorKind::OutOfRange {
+                min: -1000,
+                max: 1000
+            }
+        );
+        assert_eq!(
+            kind("/process/rlimits/1/type"),
+            &ValidationErrorKind::Duplicate
+        );
+    }
+
+    /// Docker's default seccomp profile for amd64, in the form it takes in `config.json`.
+    const DOCKER_SECCOMP: &str = include_str!("../testdata/seccomp-docker-default-amd64.json");
+
+    #[test]
+    fn seccomp_docker_default_profile() {
+        let profile: Value = serde_json::from_str(DOCKER_SECCOMP).unwrap();
+        let spec = spec(json!({"linux": {"seccomp": profile}}));
+        assert_eq!(paths(&spec), Vec::<String>::new());
+        let seccomp = serde_json::to_value(spec.linux().unwrap().seccomp()).unwrap();
+        assert_eq!(seccomp, profile);
+    }
+
+    #[test]
+    fn seccomp_notify_requires_a_listener() {
+        let notify = json!([{"names": ["mkdir"], "action": "SCMP_ACT_NOTIFY"}]);
+        let with_listener = spec(json!({"linux": {"seccomp": {
+            "defaultAction": "SCMP_ACT_ALLOW",
+            "listenerPath": "/run/seccomp-agent.sock",
+            "listenerMetadata": "profile=default",
+            "syscalls": notify,
+        }}}));
+        assert!(with_listener.validate().is_ok());
+
+        let without_listener = spec(json!({"linux": {"seccomp": {
+            "defaultAction": "SCMP_ACT_NOTIFY",
+            "listenerMetadata": "profile=default",
+            "syscalls": notify,
+        }}}));
+        assert_eq!(
+            paths(&without_listener),
+            [
+                "/linux/seccomp/defaultAction",
+                "/linux/seccomp/listenerMetadata",
+                "/linux/seccomp/listenerPath",
+            ]
+        );
+
+        let relative = spec(json!({"linux": {"seccomp": {
+            "defaultAction": "SCMP_ACT_ALLOW",
+            "listenerPath": "agent.sock",
+        }}}));
+        assert_eq!(paths(&relative), ["/linux/seccomp/listenerPath"]);
+    }
+
+    #[test]
+    fn seccomp_errno_requires_errno_or_trace() {
+        let spec = spec(json!({"linux": {"seccomp": {
+            "defaultAction": "SCMP_ACT_KILL",
+            "defaultErrnoRet": 1,
+            "syscalls": [
+                {"names": ["mount"], "action": "SCMP_ACT_TRACE", "errnoRet": 1},
+                {"names": ["umount2"], "action": "SCMP_ACT_ALLOW", "errnoRet": 1},
+                {"names": [], "action": "SCMP_ACT_ALLOW"},
+            ],
+        }}}));
+        assert_eq!(
+            paths(&spec),
+            [
+                "/linux/seccomp/defaultErrnoRet",
+                "/linux/seccomp/syscalls/1/errnoRet",
+                "/linux/seccomp/syscalls/2/names",
+            ]
+        );
+    }
+
+    #[test]
+    fn time_offsets_require_a_new_time_namespace() {
+        let offsets = json!({"monotonic": {"secs": 86400}, "boottime": {"nanosecs": 5}});
+        let new_ns = spec(json!({"linux": {
+            "timeOffsets": offsets,
+            "namespaces": namespaces(json!([{"type": "time"}])),
+        }}));
+        assert!(new_ns.validate().is_ok());
+
+        let joined = spec(json!({"linux": {
+            "timeOffsets": offsets,
+            "namespaces": namespaces(json!([{"type": "time", "path": "/proc/1/ns/time"}])),
+        }}));
+        assert_eq!(paths(&joined), ["/linux/timeOffsets"]);
+
+        let missing = spec(json!({"linux": {"timeOffsets": offsets}}));
+        assert_eq!(paths(&missing), ["/linux/timeOffsets"]);
+    }
+
+    #[test]
+    fn time_offsets_check_clocks_and_nanoseconds() {
+        let spec = spec(json!({"linux": {
+            "namespaces": namespaces(json!([{"type": "time"}])),
+            "timeOffsets": {"realtime": {"secs": 1}, "boottime": {"nanosecs": 1_000_000_000u32}},
+        }}));
+        assert_eq!(
+            paths(&spec),
+            [
+                "/linux/timeOffsets/boottime/nanosecs",
+                "/linux/timeOffsets/realtime"
+            ]
+        );
+    }
+
+    #[test]
+    fn unified_cpu_weight_ranges() {
+        let mut spec = Spec::default_linux();
+        resources(&mut spec).set_cpu_weight(0);
+        resources(&mut spec).set_cpu_weight_nice(-100);
+        assert_eq!(
+            paths(&spec),
+            [
+                "/linux/resources/unified/cpu.weight",
+                "/linux/resources/unified/cpu.weight.nice",
+            ]
+        );
+
+        resources(&mut spec).set_cpu_weight(10000);
+        resources(&mut spec).set_cpu_weight_nice(-20);
+        assert!(spec.validate().is_ok());
+
+        resources(&mut spec).set_unified("cpu.weight", "heavy");
+        assert_eq!(paths(&spec), ["/linux/resources/unified/cpu.weight"]);
+    }
+
+    #[test]
+    fn unified_cpu_weight_nice_conflicts_with_shares() {
+        let spec = spec(json!({"linux": {"resources": {
+            "cpu": {"shares": 1024},
+            "unified": {"cpu.weight.nice": "0", "cpu.idle": "1"},
+        }}}));
+        assert_eq!(paths(&spec), ["/linux/resources/unified/cpu.weight.nice"]);
+    }
+}